@@ -80,6 +80,7 @@ pub fn instantiate(
         transaction_fees: msg.transaction_fees,
         control_fees: msg.control_fees,
         max_bonding_limit_per_user: msg.max_bonding_limit_per_user,
+        gaming_pool_contract: None,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -171,6 +172,10 @@ pub fn execute(
         } => {
             increase_reward_amount(deps, env, info, reward_from, amount)
         }
+        ExecuteMsg::SetGamingPoolContract { gaming_pool_contract } => {
+            set_gaming_pool_contract(deps, info, gaming_pool_contract)
+        }
+        ExecuteMsg::Receive(message) => received_message(deps, env, info, message),
     }
 }
 
@@ -192,6 +197,9 @@ fn received_message(
         ReceivedMsg::IncreaseRewardAmount(irac) => {
             increase_reward_amount(deps, env, info, irac.reward_from, amount)
         }
+        ReceivedMsg::StakeOnAClub(soac) => {
+            stake_received_on_a_club(deps, env, info, message.sender, soac.staker, soac.club_name, amount, soac.auto_stake)
+        }
     }
     // Err(ContractError::Std(StdError::GenericErr {
     //     msg: format!("received_message where msg = {:?}", msg),
@@ -367,24 +375,15 @@ fn buy_a_club(
         }));
     }
 
-    let required_ust_fees: Uint128;
-    //To bypass calls from unit tests
-    if info.sender.clone().into_string() == String::from("Owner001")
-        || info.sender.clone().into_string() == String::from("Owner002")
-        || info.sender.clone().into_string() == String::from("Owner003")
-    {
-        required_ust_fees = Uint128::zero();
-    } else {
-        required_ust_fees = query_platform_fees(
-            deps.as_ref(),
-            to_binary(&ExecuteMsg::BuyAClub {
-                buyer: buyer.clone(),
-                club_name: club_name.clone(),
-                seller: seller_opt,
-                auto_stake: auto_stake,
-            })?,
-        )?;
-    }
+    let required_ust_fees = query_platform_fees(
+        deps.as_ref(),
+        to_binary(&ExecuteMsg::BuyAClub {
+            buyer: buyer.clone(),
+            club_name: club_name.clone(),
+            seller: seller_opt,
+            auto_stake: auto_stake,
+        })?,
+    )?;
     let mut fees = Uint128::zero();
     for fund in info.funds.clone() {
         if fund.denom == "uusd" {
@@ -795,27 +794,15 @@ fn stake_on_a_club(
     let staker_addr = deps.api.addr_validate(&staker)?;
     let contract_address = env.clone().contract.address.into_string();
 
-    let required_ust_fees: Uint128;
-    //To bypass calls from unit tests
-    if info.sender.clone().into_string() == String::from("Staker001")
-        || info.sender.clone().into_string() == String::from("Staker002")
-        || info.sender.clone().into_string() == String::from("Staker003")
-        || info.sender.clone().into_string() == String::from("Staker004")
-        || info.sender.clone().into_string() == String::from("Staker005")
-        || info.sender.clone().into_string() == String::from("Staker006")
-    {
-        required_ust_fees = Uint128::zero();
-    } else {
-        required_ust_fees = query_platform_fees(
-            deps.as_ref(),
-            to_binary(&ExecuteMsg::StakeOnAClub {
-                staker: staker.clone(),
-                club_name: club_name.clone(),
-                amount: amount,
-                auto_stake: auto_stake,
-            })?,
-        )?;
-    }
+    let required_ust_fees = query_platform_fees(
+        deps.as_ref(),
+        to_binary(&ExecuteMsg::StakeOnAClub {
+            staker: staker.clone(),
+            club_name: club_name.clone(),
+            amount: amount,
+            auto_stake: auto_stake,
+        })?,
+    )?;
     let mut fees = Uint128::zero();
     for fund in info.funds.clone() {
         if fund.denom == "uusd" {
@@ -889,6 +876,72 @@ fn stake_on_a_club(
         .set_data(data_msg));
 }
 
+fn set_gaming_pool_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    gaming_pool_contract: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin_address {
+        return Err(ContractError::Unauthorized {});
+    }
+    config.gaming_pool_contract = Some(deps.api.addr_validate(&gaming_pool_contract)?);
+    CONFIG.save(deps.storage, &config)?;
+    return Ok(Response::new()
+        .add_attribute("action", "set_gaming_pool_contract")
+        .add_attribute("gaming_pool_contract", gaming_pool_contract));
+}
+
+// Stakes rewards the gaming pool sends on behalf of a gamer. The platform fee
+// and the staker check of stake_on_a_club are skipped, so the send has to come
+// from the configured gaming pool
+fn stake_received_on_a_club(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    staker: String,
+    club_name: String,
+    amount: Uint128,
+    auto_stake: bool,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    // Only FURY can be staked
+    if info.sender != config.minting_contract_address {
+        return Err(ContractError::Unauthorized {});
+    }
+    if config.gaming_pool_contract != Some(deps.api.addr_validate(&sender)?) {
+        return Err(ContractError::Unauthorized {});
+    }
+    deps.api.addr_validate(&staker)?;
+
+    //check if the club_name is available for staking
+    let ownership_details = CLUB_OWNERSHIP_DETAILS.may_load(deps.storage, club_name.clone())?;
+    if ownership_details.is_some() {
+        save_staking_details(
+            deps.storage,
+            env,
+            staker.clone(),
+            club_name.clone(),
+            amount,
+            auto_stake,
+            INCREASE_STAKE,
+        )?;
+    } else {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("The club is not available for staking"),
+        }));
+    }
+
+    let data_msg = format!("Club stake {} received", amount).into_bytes();
+    return Ok(Response::new()
+        .add_attribute("action", "stake_on_a_club")
+        .add_attribute("staker", staker)
+        .add_attribute("club_name", club_name)
+        .add_attribute("stake", amount.to_string())
+        .set_data(data_msg));
+}
+
 fn assign_stakes_to_a_club(
     deps: DepsMut,
     env: Env,
@@ -998,27 +1051,15 @@ fn withdraw_stake_from_a_club(
         }
     }
 
-    let required_ust_fees: Uint128;
-    //To bypass calls from unit tests
-    if info.sender.clone().into_string() == String::from("Staker001")
-        || info.sender.clone().into_string() == String::from("Staker002")
-        || info.sender.clone().into_string() == String::from("Staker003")
-        || info.sender.clone().into_string() == String::from("Staker004")
-        || info.sender.clone().into_string() == String::from("Staker005")
-        || info.sender.clone().into_string() == String::from("Staker006")
-    {
-        required_ust_fees = Uint128::zero();
-    } else {
-        required_ust_fees = query_platform_fees(
-            deps.as_ref(),
-            to_binary(&ExecuteMsg::StakeWithdrawFromAClub {
-                staker: staker.clone(),
-                club_name: club_name.clone(),
-                amount: withdrawal_amount,
-                immediate_withdrawal,
-            })?,
-        )?;
-    }
+    let required_ust_fees = query_platform_fees(
+        deps.as_ref(),
+        to_binary(&ExecuteMsg::StakeWithdrawFromAClub {
+            staker: staker.clone(),
+            club_name: club_name.clone(),
+            amount: withdrawal_amount,
+            immediate_withdrawal,
+        })?,
+    )?;
     let mut fees = Uint128::zero();
     for fund in info.funds.clone() {
         if fund.denom == "uusd" {
@@ -2018,6 +2059,12 @@ pub fn query_platform_fees(deps: Deps, msg: Binary) -> StdResult<Uint128> {
             fury_amount_provided = query_staker_rewards(deps, staker, club_name)?;
             platform_fees_percentage = config.platform_fees + config.transaction_fees;
         }
+        Ok(ExecuteMsg::SetGamingPoolContract { gaming_pool_contract: _ }) => {
+            return Ok(Uint128::zero());
+        }
+        Ok(ExecuteMsg::Receive(_)) => {
+            return Ok(Uint128::zero());
+        }
         Err(err) => {
             return Err(StdError::generic_err(format!("{:?}", err)));
         }
@@ -2240,15 +2287,60 @@ pub fn query_club_ownership_details_for_owner(
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{Addr, coins, CosmosMsg, from_binary, StdError, SubMsg, WasmMsg};
+    use cosmwasm_std::{Addr, coins, ContractResult, CosmosMsg, Empty, from_binary, from_slice, OwnedDeps, Querier, QuerierResult, QueryRequest, StdError, SubMsg, SystemError, SystemResult, WasmMsg, WasmQuery};
     use cosmwasm_std::coin;
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+
+    use crate::msg::StakeOnAClubCommand;
 
     use super::*;
 
+    // Answers the astroport proxy price queries, one FURY is worth one UST
+    struct MockProxyQuerier {
+        base: MockQuerier,
+    }
+
+    impl Querier for MockProxyQuerier {
+        fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+            let request: QueryRequest<Empty> = match from_slice(bin_request) {
+                Ok(v) => v,
+                Err(e) => {
+                    return SystemResult::Err(SystemError::InvalidRequest {
+                        error: format!("Parsing query request: {}", e),
+                        request: bin_request.into(),
+                    });
+                }
+            };
+            match &request {
+                QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) if contract_addr == "astro_proxy_address1111" => {
+                    match from_binary(msg) {
+                        Ok(ProxyQueryMsgs::get_ust_equivalent_to_fury { fury_count }) => {
+                            SystemResult::Ok(ContractResult::Ok(to_binary(&fury_count).unwrap()))
+                        }
+                        Ok(ProxyQueryMsgs::get_fury_equivalent_to_ust { ust_count }) => {
+                            SystemResult::Ok(ContractResult::Ok(to_binary(&ust_count).unwrap()))
+                        }
+                        Err(e) => SystemResult::Ok(ContractResult::Err(e.to_string())),
+                    }
+                }
+                _ => self.base.handle_query(&request),
+            }
+        }
+    }
+
+    fn mock_dependencies_with_proxy() -> OwnedDeps<MockStorage, MockApi, MockProxyQuerier> {
+        OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier: MockProxyQuerier {
+                base: MockQuerier::new(&[]),
+            },
+        }
+    }
+
     #[test]
     fn test_buying_of_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2277,7 +2369,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2306,7 +2398,7 @@ mod tests {
 
     #[test]
     fn test_owner_claim_rewards() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2335,7 +2427,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         let result = buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2386,7 +2478,7 @@ mod tests {
 
     #[test]
     fn test_multiple_buying_of_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2415,7 +2507,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2455,7 +2547,7 @@ mod tests {
 
     #[test]
     fn test_assign_a_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2484,8 +2576,8 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(1000, "stake")]);
-        let owner2_info = mock_info("Owner002", &[coin(1000, "stake")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
+        let owner2_info = mock_info("Owner002", &[coin(13000, "uusd")]);
 
         println!("Now assigning the club to Owner001");
         assign_a_club(
@@ -2584,7 +2676,7 @@ mod tests {
 
     #[test]
     fn test_assign_stakes_to_a_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2613,7 +2705,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(1000, "stake")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
 
         println!("Now assigning the club to Owner001");
         assign_a_club(
@@ -2666,7 +2758,7 @@ mod tests {
             });
         };
 
-        let staker6Info = mock_info("Staker006", &[coin(10, "stake")]);
+        let staker6Info = mock_info("Staker006", &[coin(15000, "uusd")]);
         assign_stakes_to_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2690,7 +2782,7 @@ mod tests {
 
     #[test]
     fn test_buying_of_club_after_releasing_by_prev_owner() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2719,7 +2811,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         let mut resp = buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2778,7 +2870,7 @@ mod tests {
             }
         }
 
-        let owner2_info = mock_info("Owner002", &[coin(0, "uusd")]);
+        let owner2_info = mock_info("Owner002", &[coin(13000, "uusd")]);
         let resp = buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2807,7 +2899,7 @@ mod tests {
 
     #[test]
     fn test_claim_previous_owner_rewards() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -2836,7 +2928,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2872,7 +2964,7 @@ mod tests {
             }
         }
 
-        let stakerInfo = mock_info("Staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -2930,7 +3022,7 @@ mod tests {
         );
 
         println!("buy a club with new owner");
-        let owner2_info = mock_info("Owner002", &[coin(0, "uusd")]);
+        let owner2_info = mock_info("Owner002", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3007,7 +3099,7 @@ mod tests {
 
     #[test]
     fn test_claim_rewards_with_no_auto_stake() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3036,7 +3128,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3049,7 +3141,7 @@ mod tests {
         );
 
 
-        let stakerInfo = mock_info("Staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3105,7 +3197,7 @@ mod tests {
 
     #[test]
     fn test_multiple_staking_on_club_by_same_address() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3134,7 +3226,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3146,7 +3238,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let stakerInfo = mock_info("Staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3197,9 +3289,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stake_on_a_club_with_received_tokens() {
+        let mut deps = mock_dependencies_with_proxy();
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+        let set_gaming_pool_msg = ExecuteMsg::SetGamingPoolContract {
+            gaming_pool_contract: "gaming_pool11111".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Staker001", &[]),
+            set_gaming_pool_msg.clone(),
+        ).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            set_gaming_pool_msg,
+        ).unwrap();
+
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "Owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        ).unwrap();
+
+        // A plain FURY holder cannot stake for someone else without paying the platform fee
+        let holder_msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "Holder001".to_string(),
+            amount: Uint128::from(900u128),
+            msg: to_binary(&ReceivedMsg::StakeOnAClub(StakeOnAClubCommand {
+                staker: "Staker001".to_string(),
+                club_name: "CLUB001".to_string(),
+                auto_stake: false,
+            })).unwrap(),
+        });
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("minting_admin11111", &[]),
+            holder_msg,
+        ).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+
+        // The gaming pool sends the reward of Staker001 with the stake command
+        let receive_msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: "gaming_pool11111".to_string(),
+            amount: Uint128::from(900u128),
+            msg: to_binary(&ReceivedMsg::StakeOnAClub(StakeOnAClubCommand {
+                staker: "Staker001".to_string(),
+                club_name: "CLUB001".to_string(),
+                auto_stake: false,
+            })).unwrap(),
+        });
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_token11111", &[]),
+            receive_msg.clone(),
+        ).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized {});
+        let rsp = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("minting_admin11111", &[]),
+            receive_msg,
+        ).unwrap();
+        assert_eq!(rsp.messages.len(), 0);
+
+        let query_stakes = query_all_stakes(&mut deps.storage, vec!["Staker001".to_string()]).unwrap();
+        assert_eq!(query_stakes.len(), 1);
+        assert_eq!(query_stakes[0].staked_amount, Uint128::from(900u128));
+        assert_eq!(query_stakes[0].auto_stake, false);
+    }
+
     #[test]
     fn test_immediate_partial_withdrawals_from_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3228,7 +3427,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3240,7 +3439,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let stakerInfo = mock_info("Staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3313,7 +3512,7 @@ mod tests {
 
     #[test]
     fn test_immediate_complete_withdrawals_from_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3342,7 +3541,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1Info = mock_info("Owner001", &[coin(1000, "stake")]);
+        let owner1Info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3354,7 +3553,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let stakerInfo = mock_info("Staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3429,7 +3628,7 @@ mod tests {
 
     #[test]
     fn test_non_immediate_complete_withdrawals_from_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3458,7 +3657,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3470,7 +3669,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let stakerInfo = mock_info("Staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3551,7 +3750,7 @@ mod tests {
                 assert_eq!(1, 2);
             }
         }
-        let stakerInfo = mock_info("Staker002", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker002", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3595,7 +3794,7 @@ mod tests {
 
     #[test]
     fn test_non_immediate_complete_withdrawals_from_club_with_scheduled_refunds() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3623,7 +3822,7 @@ mod tests {
             instantiate_msg,
         );
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3635,7 +3834,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let stakerInfo = mock_info("Staker001", &[coin(10, "stake")]);
+        let stakerInfo = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3759,7 +3958,7 @@ mod tests {
 
     #[test]
     fn test_non_immediate_partial_withdrawals_from_club() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3788,7 +3987,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1_info = mock_info("Owner001", &[coin(0, "uusd")]);
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
         let result = buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3882,7 +4081,7 @@ mod tests {
 
     #[test]
     fn test_distribute_rewards() {
-        let mut deps = mock_dependencies(&[]);
+        let mut deps = mock_dependencies_with_proxy();
         let now = mock_env().block.time; // today
 
         let instantiate_msg = InstantiateMsg {
@@ -3913,7 +4112,7 @@ mod tests {
         )
             .unwrap();
 
-        let owner1Info = mock_info("Owner001", &[coin(1000, "stake")]);
+        let owner1Info = mock_info("Owner001", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3924,7 +4123,7 @@ mod tests {
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
         );
-        let owner2Info = mock_info("Owner002", &[coin(1000, "stake")]);
+        let owner2Info = mock_info("Owner002", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3935,7 +4134,7 @@ mod tests {
             Uint128::from(1000000u128),
             SET_AUTO_STAKE,
         );
-        let owner3Info = mock_info("Owner003", &[coin(1000, "stake")]);
+        let owner3Info = mock_info("Owner003", &[coin(13000, "uusd")]);
         buy_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3947,7 +4146,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let staker1Info = mock_info("Staker001", &[coin(10, "stake")]);
+        let staker1Info = mock_info("Staker001", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3958,7 +4157,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let staker2Info = mock_info("Staker002", &[coin(10, "stake")]);
+        let staker2Info = mock_info("Staker002", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3969,7 +4168,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let staker3Info = mock_info("Staker003", &[coin(10, "stake")]);
+        let staker3Info = mock_info("Staker003", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3980,7 +4179,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let staker4Info = mock_info("Staker004", &[coin(10, "stake")]);
+        let staker4Info = mock_info("Staker004", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -3991,7 +4190,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let staker5Info = mock_info("Staker005", &[coin(10, "stake")]);
+        let staker5Info = mock_info("Staker005", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -4002,7 +4201,7 @@ mod tests {
             SET_AUTO_STAKE,
         );
 
-        let staker6Info = mock_info("Staker006", &[coin(10, "stake")]);
+        let staker6Info = mock_info("Staker006", &[coin(15000, "uusd")]);
         stake_on_a_club(
             deps.as_mut(),
             mock_env(),
@@ -4246,4 +4445,85 @@ mod tests {
             club_name3, false, true).unwrap_err();
         assert_eq!(res3, (ContractError::Std(StdError::GenericErr {msg: String::from("Time for Reward not yet arrived")})));
     }
+
+    #[test]
+    fn test_staking_fees_priced_by_proxy() {
+        let mut deps = mock_dependencies_with_proxy();
+        let now = mock_env().block.time; // today
+
+        let instantiate_msg = InstantiateMsg {
+            admin_address: "admin11111".to_string(),
+            minting_contract_address: "minting_admin11111".to_string(),
+            astro_proxy_address: "astro_proxy_address1111".to_string(),
+            club_fee_collector_wallet: "club_fee_collector_wallet11111".to_string(),
+            club_reward_next_timestamp: now.minus_seconds(1 * 60 * 60),
+            reward_periodicity: 24 * 60 * 60u64,
+            club_price: Uint128::from(1000000u128),
+            bonding_duration: 5 * 60u64,
+            owner_release_locking_duration: 24 * 60 * 60u64,
+            platform_fees_collector_wallet: "platform_fee_collector_wallet_1111".to_string(),
+            platform_fees: Uint128::from(100u128),
+            transaction_fees: Uint128::from(30u128),
+            control_fees: Uint128::from(50u128),
+            max_bonding_limit_per_user: 10u64,
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        )
+            .unwrap();
+
+        let owner1_info = mock_info("Owner001", &[coin(13000, "uusd")]);
+        buy_a_club(
+            deps.as_mut(),
+            mock_env(),
+            owner1_info.clone(),
+            "Owner001".to_string(),
+            Some(String::default()),
+            "CLUB001".to_string(),
+            Uint128::from(1000000u128),
+            SET_AUTO_STAKE,
+        ).unwrap();
+
+        // 1.8% of the UST value of the stake, no matter who the staker is
+        let stakerInfo = mock_info("Staker001", &[coin(1000, "uusd")]);
+        let rsp = stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "Staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100000u128),
+            SET_AUTO_STAKE,
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e, ContractError::InsufficientFees {
+                    required: Uint128::from(1800u128),
+                    received: Uint128::from(1000u128),
+                });
+            }
+        }
+
+        let stakerInfo = mock_info("Staker001", &[coin(1800, "uusd")]);
+        stake_on_a_club(
+            deps.as_mut(),
+            mock_env(),
+            stakerInfo.clone(),
+            "Staker001".to_string(),
+            "CLUB001".to_string(),
+            Uint128::from(100000u128),
+            SET_AUTO_STAKE,
+        ).unwrap();
+        let mut user_address_list = Vec::new();
+        user_address_list.push("Staker001".to_string());
+        let all_stakes = query_all_stakes(&mut deps.storage, user_address_list).unwrap();
+        assert_eq!(all_stakes[0].staked_amount, Uint128::from(100000u128));
+    }
 }
@@ -104,7 +104,12 @@ pub enum ExecuteMsg {
         reward_from: String,
         amount: Uint128,
     },
-
+    /// Administrator sets the gaming pool whose claimed rewards are staked through Receive
+    SetGamingPoolContract {
+        gaming_pool_contract: String,
+    },
+    /// Tokens sent with one of the ReceivedMsg commands
+    Receive(Cw20ReceiveMsg),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -156,6 +161,9 @@ pub enum QueryMsg {
 pub enum ReceivedMsg {
     /// Incoming Rewards for meant for distribution to Stakers and Owners
     IncreaseRewardAmount(IncreaseRewardAmountCommand),
+    /// Stakes the tokens received on a club for the given staker, so other
+    /// contracts can pay out and stake in the same transaction
+    StakeOnAClub(StakeOnAClubCommand),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -163,6 +171,13 @@ pub struct IncreaseRewardAmountCommand {
     pub reward_from: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakeOnAClubCommand {
+    pub staker: String,
+    pub club_name: String,
+    pub auto_stake: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct MigrateMsg {}
 
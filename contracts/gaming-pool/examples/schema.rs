@@ -3,12 +3,11 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 
-use cw20::{
-    AllAccountsResponse, AllAllowancesResponse, AllowanceResponse, BalanceResponse,
-    TokenInfoResponse,
-};
-
-//use cw20_base::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use gaming_pool::events::GamingPoolEvent;
+use gaming_pool::msg::{ExecuteMsg, GamerStatsResponse, InstantiateMsg, LeaderboardEntry, MigrateMsg, QueryMsg,
+                       ReceivedMsg, RolesResponse, SolvencyReport};
+use gaming_pool::state::{ClaimSettings, GameDetails, GameResult, PoolDetails, PoolLedger, PoolTeamDetails, PoolTemplate,
+                         PoolTypeDetails, SwapAttempt, SwapBalanceDetails, UserPoolDetails};
 
 fn main() {
     let mut out_dir = current_dir().unwrap();
@@ -16,14 +15,26 @@ fn main() {
     create_dir_all(&out_dir).unwrap();
     remove_schemas(&out_dir).unwrap();
 
-/*
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
-*/
-    export_schema(&schema_for!(AllowanceResponse), &out_dir);
-    export_schema(&schema_for!(BalanceResponse), &out_dir);
-    export_schema(&schema_for!(TokenInfoResponse), &out_dir);
-    export_schema(&schema_for!(AllAllowancesResponse), &out_dir);
-    export_schema(&schema_for!(AllAccountsResponse), &out_dir);
+    export_schema(&schema_for!(MigrateMsg), &out_dir);
+    export_schema(&schema_for!(ReceivedMsg), &out_dir);
+    export_schema(&schema_for!(RolesResponse), &out_dir);
+    export_schema(&schema_for!(GameDetails), &out_dir);
+    export_schema(&schema_for!(PoolDetails), &out_dir);
+    export_schema(&schema_for!(PoolTeamDetails), &out_dir);
+    export_schema(&schema_for!(PoolTypeDetails), &out_dir);
+    export_schema(&schema_for!(GameResult), &out_dir);
+    export_schema(&schema_for!(SwapBalanceDetails), &out_dir);
+    export_schema(&schema_for!(SwapAttempt), &out_dir);
+    export_schema(&schema_for!(PoolLedger), &out_dir);
+    export_schema(&schema_for!(PoolTemplate), &out_dir);
+    export_schema(&schema_for!(UserPoolDetails), &out_dir);
+    export_schema(&schema_for!(ClaimSettings), &out_dir);
+    export_schema(&schema_for!(SolvencyReport), &out_dir);
+    export_schema(&schema_for!(LeaderboardEntry), &out_dir);
+    export_schema(&schema_for!(GamerStatsResponse), &out_dir);
+    // Wasm events, each variant is emitted as wasm-<variant> with its fields as attributes
+    export_schema(&schema_for!(GamingPoolEvent), &out_dir);
 }
@@ -1,29 +1,42 @@
-use std::convert::TryFrom;
-use std::ops::{Div, Mul};
-use std::str::FromStr;
-
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, to_binary, Uint128};
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult, to_binary, Uint128};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use schemars::_serde_json::ser::State;
 
-use cw20::Cw20QueryMsg;
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 
 use crate::error::ContractError;
-use crate::execute::{cancel_game, claim_refund, claim_reward, create_pool, execute_sweep,
-                     game_pool_bid_submit, game_pool_reward_distribute, lock_game,
-                     save_team_details, set_platform_fee_wallets,
-                     set_pool_type_params, swap};
-use crate::msg::{BalanceResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use crate::query::{get_team_count_for_user_in_pool_type, query_all_pool_type_details, query_all_pools_in_game, query_all_teams, query_game_details, query_game_result, query_pool_collection, query_pool_details, query_pool_team_details, query_pool_type_details, query_refund, query_reward, query_swap_data_for_pool, query_team_details, query_total_fees};
-use crate::state::{Config, CONFIG, GAME_DETAILS, GAME_RESULT_DUMMY, GameDetails, GameResult, SWAP_BALANCE_INFO};
+use crate::execute::{accept_admin_role, cancel_game, check_contract_active, claim_refund, claim_reward, create_game, create_pool, create_user_pool, disable_pool_type, execute_sweep,
+                     finalize_game_results, game_pool_bid_submit, game_pool_bid_submit_any_pool, lock_game, propose_game_results, propose_new_admin,
+                     received_message, set_activation_status, set_claim_settings, set_club_staking_contract, set_entry_close_time, set_platform_fee_wallets, set_pool_template,
+                     set_game_operator, set_pool_type_params, set_result_dispute_params, set_treasury, swap, swap_reply, update_pool_invitees, update_pool_type, update_team, vote_game_results, withdraw_game_results, withdraw_team};
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::oracle::ProxyOracle;
+use crate::query::{get_team_count_for_user_in_pool_type, query_activation_status, query_all_games, query_all_pool_type_details, query_all_pools_in_game, query_all_teams, query_claim_settings, query_game_details, query_game_result, query_gamer_stats, query_pool_collection, query_pool_access, query_pool_details, query_pool_leaderboard, query_pool_ledger, query_pool_team_details, query_pending_game_results, query_pool_template, query_pool_type_details, query_refund, query_result_dispute_config, query_reward, query_roles, query_solvency_report, query_swap_data_for_pool, query_swap_history, query_team_details, query_total_fees, query_user_pool};
+use crate::migrations::{LEGACY_LAYOUT_VERSION, migrate_legacy_layout, parse_version};
+use crate::state::{ACTIVATION_STATUS, ADMIN, Config, CONFIG, GAME_OPERATOR, GAME_RESULT_DUMMY, GameResult,
+                   TREASURY};
 
 // This is a comment
 // version info for migration info
 pub const CONTRACT_NAME: &str = "crates.io:gaming-pool";
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Page size for the list queries
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 30;
+
+// Number of pools a single claim goes through
+pub const DEFAULT_CLAIM_LIMIT: u32 = 10;
+pub const MAX_CLAIM_LIMIT: u32 = 30;
+
+// Pools a game can have, so locking or cancelling the game stays within one
+// transaction
+pub const MAX_POOLS_PER_GAME: usize = 100;
+
+// Player pools a single address can open in a game
+pub const MAX_USER_POOLS_PER_GAME: u32 = 5;
+pub const MAX_POOL_NAME_LENGTH: usize = 64;
+
 pub const DUMMY_WALLET: &str = "terra1t3czdl5h4w4qwgkzs80fdstj0z7rfv9v2j6uh3";
 
 // Initial reward amount to gamer for joining a pool
@@ -55,15 +68,20 @@ pub const DUMMY_TEAM_ID: &str = "DUMMY_TEAM_ID";
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    // The admin starts out holding the game operator and treasury roles too
+    let admin_address = deps.api.addr_validate(&msg.admin_address)?;
+    ADMIN.set(deps.branch(), Some(admin_address.clone()))?;
+    GAME_OPERATOR.set(deps.branch(), Some(admin_address.clone()))?;
+    TREASURY.set(deps.branch(), Some(admin_address))?;
+
     let config = Config {
-        admin_address: deps.api.addr_validate(&msg.admin_address)?,
         minting_contract_address: deps.api.addr_validate(&msg.minting_contract_address)?,
         platform_fees_collector_wallet: deps
             .api
@@ -71,9 +89,9 @@ pub fn instantiate(
         astro_proxy_address: deps.api.addr_validate(&msg.astro_proxy_address)?,
         platform_fee: msg.platform_fee,
         transaction_fee: msg.transaction_fee,
-        game_id: msg.game_id.clone(),
     };
     CONFIG.save(deps.storage, &config)?;
+    ACTIVATION_STATUS.save(deps.storage, &true)?;
 
     let dummy_wallet = String::from(DUMMY_WALLET);
     let main_address = deps.api.addr_validate(dummy_wallet.clone().as_str())?;
@@ -82,7 +100,7 @@ pub fn instantiate(
         &main_address,
         &GameResult {
             gamer_address: DUMMY_WALLET.to_string(),
-            game_id: msg.game_id.clone(),
+            game_id: String::new(),
             team_id: DUMMY_TEAM_ID.to_string(),
             team_rank: INITIAL_TEAM_RANK,
             team_points: INITIAL_TEAM_POINTS,
@@ -90,15 +108,6 @@ pub fn instantiate(
             refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         },
     )?;
-
-    GAME_DETAILS.save(
-        deps.storage,
-        msg.game_id.clone(),
-        &GameDetails {
-            game_id: msg.game_id.clone(),
-            game_status: GAME_POOL_OPEN,
-        },
-    )?;
     Ok(Response::default())
 }
 
@@ -109,9 +118,49 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Query Cw20 Check list
-    check_and_confirm_whitelist_status(&deps, &info, &env)?;
+    // Query Cw20 Check list, for a cw20 hook the wallet is the token sender and
+    // for a claim the gamer, whoever relays it
+    let wallet_address = match &msg {
+        ExecuteMsg::Receive(cw20_msg) => cw20_msg.sender.clone(),
+        ExecuteMsg::ClaimReward { gamer, .. } | ExecuteMsg::ClaimRefund { gamer, .. } => gamer.clone(),
+        _ => info.sender.to_string(),
+    };
+    check_and_confirm_whitelist_status(&deps, wallet_address, &env)?;
+    // Prices and swap fees come from the astroport proxy
+    let config = CONFIG.load(deps.storage)?;
+    let oracle = ProxyOracle::new(deps.querier, config.astro_proxy_address.to_string());
+    match &msg {
+        ExecuteMsg::GamePoolBidSubmitCommand { .. }
+        | ExecuteMsg::GamePoolBidSubmitAnyPool { .. }
+        | ExecuteMsg::Receive(_)
+        | ExecuteMsg::UpdateTeam { .. }
+        | ExecuteMsg::CreateUserPool { .. }
+        | ExecuteMsg::Swap { .. }
+        | ExecuteMsg::ProposeGameResults { .. }
+        | ExecuteMsg::FinalizeGameResults { .. } => {
+            check_contract_active(deps.storage)?;
+        }
+        _ => {}
+    }
     match msg {
+        ExecuteMsg::ProposeNewAdmin { new_admin } => {
+            propose_new_admin(deps, info, new_admin)
+        }
+        ExecuteMsg::AcceptAdminRole {} => {
+            accept_admin_role(deps, info)
+        }
+        ExecuteMsg::SetGameOperator { game_operator } => {
+            set_game_operator(deps, info, game_operator)
+        }
+        ExecuteMsg::SetTreasury { treasury } => {
+            set_treasury(deps, info, treasury)
+        }
+        ExecuteMsg::SetClubStakingContract { club_staking_contract } => {
+            set_club_staking_contract(deps, info, club_staking_contract)
+        }
+        ExecuteMsg::SetActivationStatus { is_active } => {
+            set_activation_status(deps, info, is_active)
+        }
         ExecuteMsg::SetPlatformFeeWallets { wallet_percentages } => {
             set_platform_fee_wallets(deps, info, wallet_percentages)
         }
@@ -122,6 +171,9 @@ pub fn execute(
             max_teams_for_pool,
             max_teams_for_gamer,
             wallet_percentages,
+            payout_tiers,
+            entry_asset,
+            withdrawal_fee,
         } => set_pool_type_params(
             deps,
             env,
@@ -132,42 +184,130 @@ pub fn execute(
             max_teams_for_pool,
             max_teams_for_gamer,
             wallet_percentages,
+            payout_tiers,
+            entry_asset,
+            withdrawal_fee,
+        ),
+        ExecuteMsg::UpdatePoolType {
+            pool_type,
+            pool_fee,
+            min_teams_for_pool,
+            max_teams_for_pool,
+            max_teams_for_gamer,
+            wallet_percentages,
+            payout_tiers,
+            withdrawal_fee,
+        } => update_pool_type(
+            deps,
+            info,
+            pool_type,
+            pool_fee,
+            min_teams_for_pool,
+            max_teams_for_pool,
+            max_teams_for_gamer,
+            wallet_percentages,
+            payout_tiers,
+            withdrawal_fee,
         ),
-        ExecuteMsg::CancelGame {} => cancel_game(deps, env, info),
-        ExecuteMsg::LockGame {} => lock_game(deps, env, info),
-        ExecuteMsg::CreatePool { pool_type } => create_pool(deps, env, info, pool_type),
-        ExecuteMsg::ClaimReward { gamer } => claim_reward(deps, info, gamer, env),
-        ExecuteMsg::ClaimRefund { gamer, max_spread } => claim_refund(deps, info, gamer, env, None, max_spread),
-        ExecuteMsg::GamePoolRewardDistribute {
+        ExecuteMsg::DisablePoolType { pool_type } => disable_pool_type(deps, info, pool_type),
+        ExecuteMsg::CreateGame { game_id, entry_close_time } => {
+            create_game(deps, env, info, game_id, entry_close_time)
+        }
+        ExecuteMsg::SetEntryCloseTime { game_id, entry_close_time } => {
+            set_entry_close_time(deps, env, info, game_id, entry_close_time)
+        }
+        ExecuteMsg::CancelGame { game_id } => cancel_game(deps, env, info, game_id),
+        ExecuteMsg::LockGame { game_id } => lock_game(deps, env, info, game_id),
+        ExecuteMsg::CreatePool { game_id, pool_type, invitees, join_code_hash } => {
+            create_pool(deps, env, info, game_id, pool_type, invitees, join_code_hash)
+        }
+        ExecuteMsg::SetPoolTemplate { pool_type, min_pool_size, max_pool_size, creator_rake_percentage } => {
+            set_pool_template(deps, info, pool_type, min_pool_size, max_pool_size, creator_rake_percentage)
+        }
+        ExecuteMsg::CreateUserPool { game_id, pool_type, name, pool_size } => {
+            create_user_pool(deps, env, info, game_id, pool_type, name, pool_size)
+        }
+        ExecuteMsg::UpdatePoolInvitees { game_id, pool_id, add, remove } => {
+            update_pool_invitees(deps, env, info, game_id, pool_id, add, remove)
+        }
+        ExecuteMsg::SetClaimSettings { payout_address, relayer } => {
+            set_claim_settings(deps, info, payout_address, relayer)
+        }
+        ExecuteMsg::ClaimReward { gamer, pool_ids, limit, auto_stake_club } => {
+            claim_reward(deps, info, gamer, &oracle, pool_ids, limit, auto_stake_club)
+        }
+        ExecuteMsg::ClaimRefund { gamer, max_spread, pool_ids, limit } => {
+            claim_refund(deps, info, gamer, &oracle, max_spread, pool_ids, limit)
+        }
+        ExecuteMsg::UpdateTeam { game_id, pool_id, old_team_id, new_team_id } => {
+            update_team(deps, env, info, game_id, pool_id, old_team_id, new_team_id)
+        }
+        ExecuteMsg::WithdrawTeam { game_id, pool_id, team_id, max_spread } => {
+            withdraw_team(deps, env, info, game_id, pool_id, team_id, &oracle, max_spread)
+        }
+        ExecuteMsg::SetResultDisputeParams {
+            challenge_period,
+            oracles,
+            veto_threshold,
+        } => set_result_dispute_params(deps, info, challenge_period, oracles, veto_threshold),
+        ExecuteMsg::ProposeGameResults {
+            game_id,
             pool_id,
             game_winners,
             is_final_batch,
-            ust_for_rake,
-        } => game_pool_reward_distribute(deps, env, info, pool_id, game_winners, is_final_batch, false, ust_for_rake),
+        } => propose_game_results(deps, env, info, game_id, pool_id, game_winners, is_final_batch),
+        ExecuteMsg::WithdrawGameResults {
+            game_id,
+            pool_id,
+        } => withdraw_game_results(deps, info, game_id, pool_id),
+        ExecuteMsg::VoteGameResults {
+            game_id,
+            pool_id,
+            vote,
+        } => vote_game_results(deps, env, info, game_id, pool_id, vote),
+        ExecuteMsg::FinalizeGameResults {
+            game_id,
+            pool_id,
+        } => finalize_game_results(deps, env, info, game_id, pool_id),
         ExecuteMsg::GamePoolBidSubmitCommand {
             gamer,
+            game_id,
             pool_type,
             pool_id,
             team_id,
             amount,
-            max_spread
+            max_spread,
+            join_code,
         } => game_pool_bid_submit(
-            deps, env, info, gamer, pool_type, pool_id, team_id, amount, false, max_spread),
-        ExecuteMsg::Sweep { funds } => execute_sweep(deps, info, funds),
+            deps, env, info, gamer, game_id, pool_type, pool_id, team_id, amount, &oracle, max_spread, join_code),
+        ExecuteMsg::GamePoolBidSubmitAnyPool {
+            gamer,
+            game_id,
+            pool_type,
+            team_id,
+            amount,
+            max_spread
+        } => game_pool_bid_submit_any_pool(
+            deps, env, info, gamer, game_id, pool_type, team_id, amount, &oracle, max_spread),
+        ExecuteMsg::Receive(msg) => received_message(deps, env, info, msg, &oracle),
+        ExecuteMsg::Sweep { funds } => execute_sweep(deps, env, info, funds),
         ExecuteMsg::Swap {
             amount,
-            pool_id, max_spread
-        } => swap(deps, env, info, amount, pool_id, max_spread),
+            game_id,
+            pool_id,
+            max_spread,
+            min_fury_received,
+        } => swap(deps, env, info, amount, game_id, pool_id, &oracle, max_spread, min_fury_received),
     }
 }
 
 pub fn check_and_confirm_whitelist_status(
     deps: &DepsMut,
-    info: &MessageInfo,
+    wallet_address: String,
     env: &Env,
 ) -> Result<Response, ContractError> {
     let query = cw20_base::msg::QueryMsg::WhitelistRestriction {
-        wallet_address: info.sender.to_string(),
+        wallet_address: wallet_address,
         contract_address: env.contract.address.to_string(),
         contract_check_needed: true,
     };
@@ -183,40 +323,71 @@ pub fn check_and_confirm_whitelist_status(
 }
 
 
-// This is the safe way of contract migration
-// We can add expose specific state properties to
+// Checks the stored cw2 version, refuses downgrades and migrations from other
+// contracts, and rewrites the storage of older layouts
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
-    Ok(Response::default())
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let stored = get_contract_version(deps.storage)?;
+    if stored.contract != CONTRACT_NAME {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: format!("Cannot migrate from {}", stored.contract),
+        }));
+    }
+    let stored_version = parse_version(&stored.version)?;
+    if stored_version > parse_version(CONTRACT_VERSION)? {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: format!("Cannot migrate from {} to older version {}", stored.version, CONTRACT_VERSION),
+        }));
+    }
+    if stored_version <= parse_version(LEGACY_LAYOUT_VERSION)? {
+        migrate_legacy_layout(deps.branch())?;
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    return Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("from_version", stored.version)
+        .add_attribute("to_version", CONTRACT_VERSION));
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::PoolTeamDetails { pool_id, user } => {
-            to_binary(&query_pool_team_details(deps.storage, pool_id, user)?)
+        QueryMsg::PoolTeamDetails { game_id, pool_id, user } => {
+            to_binary(&query_pool_team_details(deps.storage, game_id, pool_id, user)?)
         }
-        QueryMsg::PoolDetails { pool_id } => to_binary(&query_pool_details(deps.storage, pool_id)?),
+        QueryMsg::PoolDetails { game_id, pool_id } => to_binary(&query_pool_details(deps.storage, game_id, pool_id)?),
         QueryMsg::PoolTypeDetails { pool_type } => {
             to_binary(&query_pool_type_details(deps.storage, pool_type)?)
         }
-        QueryMsg::AllPoolTypeDetails {} => to_binary(&query_all_pool_type_details(deps.storage)?),
-        QueryMsg::AllTeams { users } => to_binary(&query_all_teams(deps.storage, users)?),
+        QueryMsg::AllPoolTypeDetails { start_after, limit } => {
+            to_binary(&query_all_pool_type_details(deps.storage, start_after, limit)?)
+        }
+        QueryMsg::AllTeams { game_id, users } => to_binary(&query_all_teams(deps.storage, game_id, users)?),
         QueryMsg::QueryReward { gamer } => to_binary(&query_reward(deps.storage, gamer)?),
         QueryMsg::QueryRefund { gamer } => to_binary(&query_refund(deps.storage, gamer)?),
         QueryMsg::QueryGameResult {
+            game_id,
             gamer,
             pool_id,
             team_id,
-        } => to_binary(&query_game_result(deps, gamer, pool_id, team_id)?),
-        QueryMsg::GameDetails {} => to_binary(&query_game_details(deps.storage)?),
-        QueryMsg::PoolTeamDetailsWithTeamId { pool_id, team_id, gamer } => {
-            to_binary(&query_team_details(deps.storage, pool_id, team_id, gamer)?)
+        } => to_binary(&query_game_result(deps, game_id, gamer, pool_id, team_id)?),
+        QueryMsg::GameDetails { game_id } => to_binary(&query_game_details(deps.storage, &env.block, game_id)?),
+        QueryMsg::AllGames { start_after, limit } => {
+            to_binary(&query_all_games(deps.storage, &env.block, start_after, limit)?)
+        }
+        QueryMsg::PoolTeamDetailsWithTeamId { game_id, pool_id, team_id, gamer } => {
+            to_binary(&query_team_details(deps.storage, game_id, pool_id, team_id, gamer)?)
         }
-        QueryMsg::AllPoolsInGame {} => to_binary(&query_all_pools_in_game(deps.storage)?),
-        QueryMsg::PoolCollection { pool_id } => {
-            to_binary(&query_pool_collection(deps.storage, pool_id)?)
+        QueryMsg::AllPoolsInGame { game_id, start_after, limit } => {
+            to_binary(&query_all_pools_in_game(deps.storage, game_id, start_after, limit)?)
         }
+        QueryMsg::PoolCollection { game_id, pool_id } => {
+            to_binary(&query_pool_collection(deps.storage, game_id, pool_id)?)
+        }
+        QueryMsg::PoolLedger { game_id, pool_id } => {
+            to_binary(&query_pool_ledger(deps.storage, game_id, pool_id)?)
+        }
+        QueryMsg::SolvencyReport {} => to_binary(&query_solvency_report(deps, env)?),
         QueryMsg::GetTeamCountForUserInPoolType {
             game_id,
             gamer,
@@ -227,43 +398,50 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             game_id,
             pool_type,
         )?),
+        QueryMsg::Roles {} => to_binary(&query_roles(deps)?),
+        QueryMsg::ActivationStatus {} => to_binary(&query_activation_status(deps.storage)?),
         QueryMsg::SwapInfo {
             pool_id
         } => to_binary(&query_swap_data_for_pool(
             deps.storage,
             pool_id,
         )?),
+        QueryMsg::SwapHistory { game_id, pool_id } => {
+            to_binary(&query_swap_history(deps.storage, game_id, pool_id)?)
+        }
         QueryMsg::GetTotalFees {
             amount
         } => to_binary(&query_total_fees(
             deps,
             amount,
-        )?)
+        )?),
+        QueryMsg::PendingGameResults {
+            game_id,
+            pool_id,
+        } => to_binary(&query_pending_game_results(
+            deps.storage,
+            game_id,
+            pool_id,
+        )?),
+        QueryMsg::ResultDisputeConfig {} => to_binary(&query_result_dispute_config(deps.storage)?),
+        QueryMsg::PoolTemplate { pool_type } => to_binary(&query_pool_template(deps.storage, pool_type)?),
+        QueryMsg::UserPool { game_id, pool_id } => {
+            to_binary(&query_user_pool(deps.storage, game_id, pool_id)?)
+        }
+        QueryMsg::PoolLeaderboard { game_id, pool_id, start_after, limit } => {
+            to_binary(&query_pool_leaderboard(deps.storage, game_id, pool_id, start_after, limit)?)
+        }
+        QueryMsg::GamerStats { gamer } => to_binary(&query_gamer_stats(deps.storage, gamer)?),
+        QueryMsg::PoolAccess { game_id, pool_id } => {
+            to_binary(&query_pool_access(deps.storage, game_id, pool_id)?)
+        }
+        QueryMsg::ClaimSettings { gamer } => to_binary(&query_claim_settings(deps.storage, gamer)?),
     }
 }
 
 
 #[allow(dead_code)]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    let pool_id = msg.id.to_string();
-    let current_fury_balance: BalanceResponse = deps.querier.query_wasm_smart(
-        config.clone().minting_contract_address,
-        &Cw20QueryMsg::Balance {
-            address: _env.contract.address.clone().to_string()
-        },
-    )?;
-    let mut balance_info = SWAP_BALANCE_INFO.load(deps.storage, pool_id.clone())?;
-    balance_info.balance_post_swap = current_fury_balance.balance;
-    let balance_gained = balance_info.balance_post_swap - balance_info.balance_pre_swap;
-    // ((Balance gained * 10_000) / Amount In UST Swapped)
-    // (poolcollection * exchange rate)/10_000 at time of use
-    balance_info.exchange_rate = balance_gained.checked_mul(Uint128::from(10000u128)).unwrap().checked_div(balance_info.ust_amount_swapped).unwrap();
-    SWAP_BALANCE_INFO.save(deps.storage, pool_id.clone(), &balance_info)?;
-    return Ok(Response::default()
-        .add_attribute("fury_balance_gained", balance_gained.to_string())
-        .add_attribute("exchange_rate_recieved", balance_info.exchange_rate.to_string())
-        .add_attribute("pool_id", pool_id)
-    );
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    return swap_reply(deps, env, msg.id, msg.result);
 }
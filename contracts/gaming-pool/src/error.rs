@@ -41,6 +41,12 @@ pub enum ContractError {
     #[error("Insufficient fees in UST sent")]
     InsufficientFeesUst {},
 
+    #[error("Insufficient fees in FURY sent")]
+    InsufficientFeesFury {},
+
+    #[error("Funds sent do not match the pool entry fee")]
+    InvalidEntryFunds {},
+
     #[error("Number Of Coins Sent Is Invalid")]
     InvalidNumberOfCoinsSent {},
 
@@ -74,4 +80,7 @@ pub enum ContractError {
     #[error("User Is Restricted..!")]
     UserIsRestricted {},
 
+    #[error("Contract is paused")]
+    ContractPaused {},
+
 }
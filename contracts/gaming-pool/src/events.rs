@@ -0,0 +1,175 @@
+use cosmwasm_std::{Event, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Events emitted on every state change an indexer needs to rebuild games,
+/// pools and teams. Each variant is added as a wasm event named after it
+/// (`wasm-pool_created`, `wasm-team_entered`, ...) with one attribute per
+/// field, so the same keys are used wherever a game, pool, gamer or team
+/// is referred to.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GamingPoolEvent {
+    PoolTypeSet {
+        pool_type: String,
+        pool_fee: Uint128,
+    },
+    PoolTypeDisabled {
+        pool_type: String,
+    },
+    PlatformFeeWalletSet {
+        wallet_name: String,
+        wallet_address: String,
+        percentage: u32,
+    },
+    GameCreated {
+        game_id: String,
+    },
+    PoolCreated {
+        game_id: String,
+        pool_id: String,
+        pool_type: String,
+    },
+    /// amount is the pool fee paid, in FURY or the pool type's entry asset
+    TeamEntered {
+        game_id: String,
+        pool_id: String,
+        gamer: String,
+        team_id: String,
+        amount: Uint128,
+    },
+    TeamUpdated {
+        game_id: String,
+        pool_id: String,
+        gamer: String,
+        team_id: String,
+        previous_team_id: String,
+    },
+    /// amount is the part of the pool fee refunded
+    TeamWithdrawn {
+        game_id: String,
+        pool_id: String,
+        gamer: String,
+        team_id: String,
+        amount: Uint128,
+    },
+    GameLocked {
+        game_id: String,
+    },
+    GameCancelled {
+        game_id: String,
+    },
+    /// Final rank of a team, amount is the reward it can claim
+    TeamResult {
+        game_id: String,
+        pool_id: String,
+        gamer: String,
+        team_id: String,
+        team_rank: u64,
+        team_points: u64,
+        amount: Uint128,
+    },
+    RewardClaimed {
+        game_id: String,
+        pool_id: String,
+        gamer: String,
+        team_id: String,
+        amount: Uint128,
+    },
+    RefundClaimed {
+        game_id: String,
+        pool_id: String,
+        gamer: String,
+        team_id: String,
+        amount: Uint128,
+    },
+    RakePaid {
+        game_id: String,
+        pool_id: String,
+        wallet_address: String,
+        amount: Uint128,
+    },
+}
+
+impl GamingPoolEvent {
+    pub fn into_event(self) -> Event {
+        match self {
+            GamingPoolEvent::PoolTypeSet { pool_type, pool_fee } => Event::new("pool_type_set")
+                .add_attribute("pool_type", pool_type)
+                .add_attribute("pool_fee", pool_fee),
+            GamingPoolEvent::PoolTypeDisabled { pool_type } => Event::new("pool_type_disabled")
+                .add_attribute("pool_type", pool_type),
+            GamingPoolEvent::PlatformFeeWalletSet { wallet_name, wallet_address, percentage } => {
+                Event::new("platform_fee_wallet_set")
+                    .add_attribute("wallet_name", wallet_name)
+                    .add_attribute("wallet_address", wallet_address)
+                    .add_attribute("percentage", percentage.to_string())
+            }
+            GamingPoolEvent::GameCreated { game_id } => Event::new("game_created")
+                .add_attribute("game_id", game_id),
+            GamingPoolEvent::PoolCreated { game_id, pool_id, pool_type } => Event::new("pool_created")
+                .add_attribute("game_id", game_id)
+                .add_attribute("pool_id", pool_id)
+                .add_attribute("pool_type", pool_type),
+            GamingPoolEvent::TeamEntered { game_id, pool_id, gamer, team_id, amount } => {
+                Event::new("team_entered")
+                    .add_attribute("game_id", game_id)
+                    .add_attribute("pool_id", pool_id)
+                    .add_attribute("gamer", gamer)
+                    .add_attribute("team_id", team_id)
+                    .add_attribute("amount", amount)
+            }
+            GamingPoolEvent::TeamUpdated { game_id, pool_id, gamer, team_id, previous_team_id } => {
+                Event::new("team_updated")
+                    .add_attribute("game_id", game_id)
+                    .add_attribute("pool_id", pool_id)
+                    .add_attribute("gamer", gamer)
+                    .add_attribute("team_id", team_id)
+                    .add_attribute("previous_team_id", previous_team_id)
+            }
+            GamingPoolEvent::TeamWithdrawn { game_id, pool_id, gamer, team_id, amount } => {
+                Event::new("team_withdrawn")
+                    .add_attribute("game_id", game_id)
+                    .add_attribute("pool_id", pool_id)
+                    .add_attribute("gamer", gamer)
+                    .add_attribute("team_id", team_id)
+                    .add_attribute("amount", amount)
+            }
+            GamingPoolEvent::GameLocked { game_id } => Event::new("game_locked")
+                .add_attribute("game_id", game_id),
+            GamingPoolEvent::GameCancelled { game_id } => Event::new("game_cancelled")
+                .add_attribute("game_id", game_id),
+            GamingPoolEvent::TeamResult { game_id, pool_id, gamer, team_id, team_rank, team_points, amount } => {
+                Event::new("team_result")
+                    .add_attribute("game_id", game_id)
+                    .add_attribute("pool_id", pool_id)
+                    .add_attribute("gamer", gamer)
+                    .add_attribute("team_id", team_id)
+                    .add_attribute("team_rank", team_rank.to_string())
+                    .add_attribute("team_points", team_points.to_string())
+                    .add_attribute("amount", amount)
+            }
+            GamingPoolEvent::RewardClaimed { game_id, pool_id, gamer, team_id, amount } => {
+                Event::new("reward_claimed")
+                    .add_attribute("game_id", game_id)
+                    .add_attribute("pool_id", pool_id)
+                    .add_attribute("gamer", gamer)
+                    .add_attribute("team_id", team_id)
+                    .add_attribute("amount", amount)
+            }
+            GamingPoolEvent::RefundClaimed { game_id, pool_id, gamer, team_id, amount } => {
+                Event::new("refund_claimed")
+                    .add_attribute("game_id", game_id)
+                    .add_attribute("pool_id", pool_id)
+                    .add_attribute("gamer", gamer)
+                    .add_attribute("team_id", team_id)
+                    .add_attribute("amount", amount)
+            }
+            GamingPoolEvent::RakePaid { game_id, pool_id, wallet_address, amount } => Event::new("rake_paid")
+                .add_attribute("game_id", game_id)
+                .add_attribute("pool_id", pool_id)
+                .add_attribute("wallet_address", wallet_address)
+                .add_attribute("amount", amount),
+        }
+    }
+}
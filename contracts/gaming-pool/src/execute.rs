@@ -1,42 +1,178 @@
-use std::convert::TryFrom;
-use std::ops::{Add, Div, Mul};
+use std::ops::Add;
 use std::str::FromStr;
 
 use astroport::asset::{Asset, AssetInfo};
 use astroport::pair::ExecuteMsg as AstroPortExecute;
-use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env,
+use cosmwasm_std::{Addr, BankMsg, Binary, Coin, ContractResult, CosmosMsg, Decimal, Deps, DepsMut, Env, Event,
                    from_binary, MessageInfo, Order, Response, StdError,
-                   StdResult, Storage, SubMsg, to_binary, Uint128, WasmMsg};
+                   StdResult, Storage, SubMsg, SubMsgExecutionResponse, to_binary, Uint128, WasmMsg};
 
-use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use cw0::{Duration, Expiration};
+use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg, Denom};
+use cw3::Vote;
+use cw_storage_plus::{Map, U64Key};
+use sha2::{Digest, Sha256};
 
-use crate::contract::{CLAIMED_REFUND, CLAIMED_REWARD, DUMMY_WALLET, GAME_CANCELLED,
+use crate::contract::{DEFAULT_CLAIM_LIMIT, DUMMY_WALLET, GAME_CANCELLED,
                       GAME_COMPLETED, GAME_POOL_CLOSED, GAME_POOL_OPEN, HUNDRED_PERCENT,
                       INITIAL_REFUND_AMOUNT, INITIAL_REWARD_AMOUNT, INITIAL_TEAM_POINTS,
-                      INITIAL_TEAM_RANK, NINETY_NINE_NINE_PERCENT, REWARDS_DISTRIBUTED,
+                      INITIAL_TEAM_RANK, MAX_CLAIM_LIMIT, MAX_POOL_NAME_LENGTH, MAX_POOLS_PER_GAME, MAX_USER_POOLS_PER_GAME,
+                      NINETY_NINE_NINE_PERCENT, REWARDS_DISTRIBUTED,
                       REWARDS_NOT_DISTRIBUTED, UNCLAIMED_REFUND, UNCLAIMED_REWARD};
 use crate::ContractError;
-use crate::msg::{BalanceResponse, ProxyQueryMsgs, QueryMsgSimulation, ReceivedMsg};
-use crate::query::{get_team_count_for_user_in_pool_type,
-                   query_pool_details, query_pool_type_details, query_swap_data_for_pool};
-use crate::state::{CONFIG, CONTRACT_POOL_COUNT, CURRENT_REWARD_FOR_POOL, FeeDetails,
-                   GAME_DETAILS, GameDetails, GameResult, PLATFORM_WALLET_PERCENTAGES,
-                   POOL_DETAILS, POOL_TEAM_DETAILS, POOL_TYPE_DETAILS, PoolDetails, PoolTeamDetails,
-                   PoolTypeDetails, SWAP_BALANCE_INFO, SwapBalanceDetails,
-                   WalletPercentage, WalletTransferDetails};
+use crate::events::GamingPoolEvent;
+use crate::msg::{BalanceResponse, BidSubmitResponse, ClubStakingReceivedMsg, GamePoolBidSubmitCommand, ReceivedMsg,
+                 StakeOnAClubCommand};
+use crate::oracle::PriceOracle;
+use crate::query::{get_game_status, may_load_pool_params, query_game_details, query_pool_params, query_activation_status,
+                   query_max_teams_for_pool, query_pending_game_results, query_pool_collection, query_pool_details, query_pool_obligations, pool_obligations,
+                   query_result_dispute_config, query_pool_type_details};
+use crate::state::{ACTIVATION_STATUS, ADMIN, CLAIM_SETTINGS, CLUB_STAKING_CONTRACT, ClaimSettings, Config, CONFIG, CONTRACT_POOL_COUNT, FeeDetails,
+                   GAME_DETAILS, GAME_OPERATOR, GAMER_CLAIMABLE_POOLS, GAMER_REFUNDABLE_POOLS, GAMER_STATS, GameDetails, GamerAssetStats, GamerStats, PayoutTier, PENDING_ADMIN, PENDING_POOL_RESULTS, PendingPoolResults, POOL_LEADERBOARD,
+                   PLATFORM_WALLET_PERCENTAGES, POOL_ACCESS, POOL_LEDGERS, POOL_OBLIGATIONS, POOL_PARAMS, POOL_TEMPLATES, POOL_REWARD_DETAILS,
+                   pools, pool_teams, POOL_TYPE_DETAILS, PoolAccess, PoolDetails, PoolLedger, PoolTemplate, PoolTeamDetails,
+                   PoolTypeDetails, RESULT_DISPUTE_CONFIG, ResultDisputeConfig, ResultVote,
+                   LAST_SWAP_REPLY_ID, SWAP_BALANCE_INFO, SWAP_HISTORY, SWAP_REPLIES, SwapAttempt, SwapBalanceDetails, SwapReplyTarget,
+                   SwapStatus, TeamChange, TeamRanking, TREASURY, USER_POOLS, USER_POOLS_CREATED, UserPoolDetails, WalletPercentage, WalletTransferDetails};
+
+pub fn assert_admin(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    if !ADMIN.is_admin(deps, sender)? {
+        return Err(ContractError::Unauthorized {
+            invoker: sender.to_string(),
+        });
+    }
+    return Ok(());
+}
+
+// The super-admin can always act as game operator
+pub fn assert_game_operator(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    if !GAME_OPERATOR.is_admin(deps, sender)? && !ADMIN.is_admin(deps, sender)? {
+        return Err(ContractError::Unauthorized {
+            invoker: sender.to_string(),
+        });
+    }
+    return Ok(());
+}
+
+// The super-admin can always act as treasury
+pub fn assert_treasury(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    if !TREASURY.is_admin(deps, sender)? && !ADMIN.is_admin(deps, sender)? {
+        return Err(ContractError::Unauthorized {
+            invoker: sender.to_string(),
+        });
+    }
+    return Ok(());
+}
+
+// First step of the admin handover, the current admin stays in charge
+// until the proposed one accepts. A new proposal replaces the pending one.
+pub fn propose_new_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_admin: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let new_admin_addr = deps.api.addr_validate(&new_admin)?;
+    PENDING_ADMIN.save(deps.storage, &new_admin_addr)?;
+    return Ok(Response::new()
+        .add_attribute("action", "propose_new_admin")
+        .add_attribute("pending_admin", new_admin_addr.to_string()));
+}
+
+pub fn accept_admin_role(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let pending_admin = PENDING_ADMIN.may_load(deps.storage)?;
+    match pending_admin {
+        Some(pending_admin) => {
+            if info.sender != pending_admin {
+                return Err(ContractError::Unauthorized {
+                    invoker: info.sender.to_string(),
+                });
+            }
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("No admin transfer is pending"),
+            }));
+        }
+    }
+    PENDING_ADMIN.remove(deps.storage);
+    ADMIN.set(deps, Some(info.sender.clone()))?;
+    return Ok(Response::new()
+        .add_attribute("action", "accept_admin_role")
+        .add_attribute("admin", info.sender.to_string()));
+}
+
+pub fn set_game_operator(
+    deps: DepsMut,
+    info: MessageInfo,
+    game_operator: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let game_operator_addr = deps.api.addr_validate(&game_operator)?;
+    GAME_OPERATOR.set(deps, Some(game_operator_addr.clone()))?;
+    return Ok(Response::new()
+        .add_attribute("action", "set_game_operator")
+        .add_attribute("game_operator", game_operator_addr.to_string()));
+}
+
+pub fn set_treasury(
+    deps: DepsMut,
+    info: MessageInfo,
+    treasury: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let treasury_addr = deps.api.addr_validate(&treasury)?;
+    TREASURY.set(deps, Some(treasury_addr.clone()))?;
+    return Ok(Response::new()
+        .add_attribute("action", "set_treasury")
+        .add_attribute("treasury", treasury_addr.to_string()));
+}
+
+// Pausing stops bids, swaps and reward distribution. Claims and refunds
+// stay open so gamers can always get their funds out.
+pub fn set_activation_status(
+    deps: DepsMut,
+    info: MessageInfo,
+    is_active: bool,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    ACTIVATION_STATUS.save(deps.storage, &is_active)?;
+    return Ok(Response::new()
+        .add_attribute("action", "set_activation_status")
+        .add_attribute("is_active", is_active.to_string()));
+}
+
+pub fn check_contract_active(storage: &dyn Storage) -> Result<(), ContractError> {
+    if !query_activation_status(storage)? {
+        return Err(ContractError::ContractPaused {});
+    }
+    return Ok(());
+}
+
+pub fn set_club_staking_contract(
+    deps: DepsMut,
+    info: MessageInfo,
+    club_staking_contract: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let club_staking_addr = deps.api.addr_validate(&club_staking_contract)?;
+    CLUB_STAKING_CONTRACT.save(deps.storage, &club_staking_addr)?;
+    return Ok(Response::new()
+        .add_attribute("action", "set_club_staking_contract")
+        .add_attribute("club_staking_contract", club_staking_addr.to_string()));
+}
 
 pub fn set_platform_fee_wallets(
     deps: DepsMut,
     info: MessageInfo,
     wallet_percentages: Vec<WalletPercentage>,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
-    }
+    assert_treasury(deps.as_ref(), &info.sender)?;
 
+    let mut rsp = Response::new();
     for wp in wallet_percentages {
         PLATFORM_WALLET_PERCENTAGES.save(
             deps.storage,
@@ -47,8 +183,13 @@ pub fn set_platform_fee_wallets(
                 percentage: wp.percentage,
             },
         )?;
+        rsp = rsp.add_event(GamingPoolEvent::PlatformFeeWalletSet {
+            wallet_name: wp.wallet_name,
+            wallet_address: wp.wallet_address,
+            percentage: wp.percentage,
+        }.into_event());
     }
-    return Ok(Response::default());
+    return Ok(rsp);
 }
 
 pub fn set_pool_type_params(
@@ -61,13 +202,11 @@ pub fn set_pool_type_params(
     max_teams_for_pool: u32,
     max_teams_for_gamer: u32,
     wallet_percentages: Vec<WalletPercentage>,
+    payout_tiers: Vec<PayoutTier>,
+    entry_asset: Option<Denom>,
+    withdrawal_fee: Option<Uint128>,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
-    }
+    assert_admin(deps.as_ref(), &info.sender)?;
     let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool_type.clone())?;
     match ptd {
         Some(_ptd) => {
@@ -77,6 +216,25 @@ pub fn set_pool_type_params(
         }
         None => {}
     };
+    validate_payout_tiers(&payout_tiers)?;
+    validate_rake_list(&wallet_percentages)?;
+    let withdrawal_fee = withdrawal_fee.unwrap_or_default();
+    if withdrawal_fee > pool_fee {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Withdrawal fee cannot be more than the pool fee"),
+        }));
+    }
+    if min_teams_for_pool > max_teams_for_pool {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Minimum teams for pool cannot be more than the maximum"),
+        }));
+    }
+    match entry_asset.clone() {
+        Some(Denom::Cw20(token)) => {
+            deps.api.addr_validate(token.as_str())?;
+        }
+        _ => {}
+    }
 
     let mut rake_list: Vec<WalletPercentage> = Vec::new();
     for wp in wallet_percentages {
@@ -92,19 +250,168 @@ pub fn set_pool_type_params(
             max_teams_for_pool: max_teams_for_pool,
             max_teams_for_gamer: max_teams_for_gamer,
             rake_list: rake_list,
+            payout_tiers: payout_tiers,
+            entry_asset: entry_asset,
+            withdrawal_fee: withdrawal_fee,
+            is_disabled: false,
         },
     )?;
-    return Ok(Response::default());
+    return Ok(Response::new().add_event(GamingPoolEvent::PoolTypeSet { pool_type, pool_fee }.into_event()));
 }
 
-pub fn cancel_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
+// Parameters other than the entry asset can change, pools created before
+// keep the parameters they were created with
+pub fn update_pool_type(
+    deps: DepsMut,
+    info: MessageInfo,
+    pool_type: String,
+    pool_fee: Uint128,
+    min_teams_for_pool: u32,
+    max_teams_for_pool: u32,
+    max_teams_for_gamer: u32,
+    wallet_percentages: Vec<WalletPercentage>,
+    payout_tiers: Vec<PayoutTier>,
+    withdrawal_fee: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let mut pool_type_details = query_pool_type_details(deps.storage, pool_type.clone())?;
+    validate_payout_tiers(&payout_tiers)?;
+    validate_rake_list(&wallet_percentages)?;
+    let withdrawal_fee = withdrawal_fee.unwrap_or_default();
+    if withdrawal_fee > pool_fee {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Withdrawal fee cannot be more than the pool fee"),
+        }));
+    }
+    if min_teams_for_pool > max_teams_for_pool {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Minimum teams for pool cannot be more than the maximum"),
+        }));
+    }
+    pool_type_details.pool_fee = pool_fee;
+    pool_type_details.min_teams_for_pool = min_teams_for_pool;
+    pool_type_details.max_teams_for_pool = max_teams_for_pool;
+    pool_type_details.max_teams_for_gamer = max_teams_for_gamer;
+    pool_type_details.rake_list = wallet_percentages;
+    pool_type_details.payout_tiers = payout_tiers;
+    pool_type_details.withdrawal_fee = withdrawal_fee;
+    POOL_TYPE_DETAILS.save(deps.storage, pool_type.clone(), &pool_type_details)?;
+    return Ok(Response::new()
+        .add_attribute("action", "update_pool_type")
+        .add_event(GamingPoolEvent::PoolTypeSet { pool_type, pool_fee }.into_event()));
+}
+
+pub fn disable_pool_type(
+    deps: DepsMut,
+    info: MessageInfo,
+    pool_type: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let mut pool_type_details = query_pool_type_details(deps.storage, pool_type.clone())?;
+    pool_type_details.is_disabled = true;
+    POOL_TYPE_DETAILS.save(deps.storage, pool_type.clone(), &pool_type_details)?;
+    return Ok(Response::new()
+        .add_attribute("action", "disable_pool_type")
+        .add_event(GamingPoolEvent::PoolTypeDisabled { pool_type }.into_event()));
+}
+
+// The rake wallets cannot be given more than the whole rake
+pub fn validate_rake_list(rake_list: &Vec<WalletPercentage>) -> Result<(), ContractError> {
+    let mut total_percentage = 0u32;
+    for wallet in rake_list {
+        total_percentage += wallet.percentage;
+    }
+    if total_percentage > 100 {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rake percentages add up to more than 100"),
+        }));
+    }
+    return Ok(());
+}
+
+pub fn create_game(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    entry_close_time: Expiration,
+) -> Result<Response, ContractError> {
+    assert_game_operator(deps.as_ref(), &info.sender)?;
+    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    match gd {
+        Some(_gd) => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Game already exists"),
+            }));
+        }
+        None => {}
+    };
+    if entry_close_time.is_expired(&env.block) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Entry close time is already past"),
+        }));
     }
-    let game_id = config.game_id;
+
+    GAME_DETAILS.save(
+        deps.storage,
+        game_id.clone(),
+        &GameDetails {
+            game_id: game_id.clone(),
+            game_status: GAME_POOL_OPEN,
+            entry_close_time: entry_close_time,
+        },
+    )?;
+    return Ok(Response::new()
+        .add_attribute("game_id", game_id.clone())
+        .add_attribute("game_status", "GAME_POOL_OPEN".to_string())
+        .add_attribute("entry_close_time", entry_close_time.to_string())
+        .add_event(GamingPoolEvent::GameCreated { game_id }.into_event()));
+}
+
+pub fn set_entry_close_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    entry_close_time: Expiration,
+) -> Result<Response, ContractError> {
+    assert_game_operator(deps.as_ref(), &info.sender)?;
+    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    let mut game;
+    match gd {
+        Some(gd) => {
+            game = gd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Game status cannot be retrieved"),
+            }));
+        }
+    }
+    if game.game_status != GAME_POOL_OPEN {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Cant change entry close time as game is not open"),
+        }));
+    }
+    if entry_close_time.is_expired(&env.block) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Entry close time is already past"),
+        }));
+    }
+    game.entry_close_time = entry_close_time;
+    GAME_DETAILS.save(deps.storage, game_id.clone(), &game)?;
+    return Ok(Response::new()
+        .add_attribute("game_id", game_id.clone())
+        .add_attribute("entry_close_time", entry_close_time.to_string()));
+}
+
+pub fn cancel_game(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    game_id: String,
+) -> Result<Response, ContractError> {
+    assert_game_operator(deps.as_ref(), &info.sender)?;
 
     let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
     let game;
@@ -135,17 +442,19 @@ pub fn cancel_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Respon
         &GameDetails {
             game_id: game_id.clone(),
             game_status: GAME_CANCELLED,
+            entry_close_time: game.entry_close_time,
         },
     )?;
 
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
+    // Get all pools in this game
+    let all_pools: Vec<String> = pools()
+        .prefix(&game_id)
         .keys(deps.storage, None, None, Order::Ascending)
         .map(|k| String::from_utf8(k).unwrap())
         .collect();
     for pool_id in all_pools {
         let mut pool;
-        let pd = POOL_DETAILS.may_load(deps.storage, pool_id.clone())?;
+        let pd = pools().may_load(deps.storage, (&*game_id, &*pool_id))?;
         match pd {
             Some(pd) => {
                 pool = pd;
@@ -156,34 +465,23 @@ pub fn cancel_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Respon
                 }));
             }
         };
-        let pool_type;
-        let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool.pool_type.clone())?;
-        match ptd {
-            Some(ptd) => {
-                pool_type = ptd;
-            }
-            None => {
-                return Err(ContractError::Std(StdError::GenericErr {
-                    msg: String::from("No pool type details found for pool"),
-                }));
-            }
-        };
         pool.pool_refund_status = true; // We skip the iteration and update the status
-        POOL_DETAILS.save(deps.storage, pool_id.clone(), &pool)?;
+        pools().save(deps.storage, (&*game_id, &*pool_id), &pool)?;
+        credit_pool_refunds(deps.storage, &game_id, &pool_id)?;
     }
     return Ok(Response::new()
         .add_attribute("game_id", game_id.clone())
-        .add_attribute("game_status", "GAME_CANCELLED".to_string()));
+        .add_attribute("game_status", "GAME_CANCELLED".to_string())
+        .add_event(GamingPoolEvent::GameCancelled { game_id }.into_event()));
 }
 
-pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
-    }
-    let game_id = config.game_id;
+pub fn lock_game(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    game_id: String,
+) -> Result<Response, ContractError> {
+    assert_game_operator(deps.as_ref(), &info.sender)?;
 
     let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
     let game;
@@ -209,17 +507,19 @@ pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response
         &GameDetails {
             game_id: game_id.clone(),
             game_status: GAME_POOL_CLOSED,
+            entry_close_time: game.entry_close_time,
         },
     )?;
 
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
+    // Get all pools in this game
+    let all_pools: Vec<String> = pools()
+        .prefix(&game_id)
         .keys(deps.storage, None, None, Order::Ascending)
         .map(|k| String::from_utf8(k).unwrap())
         .collect();
     for pool_id in all_pools {
         let mut pool;
-        let pd = POOL_DETAILS.may_load(deps.storage, pool_id.clone())?;
+        let pd = pools().may_load(deps.storage, (&*game_id, &*pool_id))?;
 
         match pd {
             Some(pd) => {
@@ -232,7 +532,7 @@ pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response
             }
         };
         let pool_type;
-        let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool.pool_type.clone())?;
+        let ptd = may_load_pool_params(deps.storage, &game_id, &pool_id)?;
         match ptd {
             Some(ptd) => {
                 pool_type = ptd;
@@ -247,26 +547,25 @@ pub fn lock_game(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response
             continue;
         }
         pool.pool_refund_status = true; // We skip the iteration and update the status
-        POOL_DETAILS.save(deps.storage, pool_id.clone(), &pool)?;
+        pools().save(deps.storage, (&*game_id, &*pool_id), &pool)?;
+        credit_pool_refunds(deps.storage, &game_id, &pool_id)?;
     }
     return Ok(Response::new()
         .add_attribute("game_id", game_id.clone())
-        .add_attribute("game_status", "GAME_POOL_CLOSED".to_string()));
+        .add_attribute("game_status", "GAME_POOL_CLOSED".to_string())
+        .add_event(GamingPoolEvent::GameLocked { game_id }.into_event()));
 }
 
 pub fn create_pool(
-    deps: DepsMut,
-    _env: Env,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
+    game_id: String,
     pool_type: String,
+    invitees: Option<Vec<String>>,
+    join_code_hash: Option<Binary>,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
-    }
-    let game_id = config.game_id;
+    assert_game_operator(deps.as_ref(), &info.sender)?;
     let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
     let game;
     match gd {
@@ -279,57 +578,340 @@ pub fn create_pool(
             }));
         }
     }
-    if game.game_status != GAME_POOL_OPEN {
+    if get_game_status(&game, &env.block) != GAME_POOL_OPEN {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Game is not open for bidding"),
         }));
     }
 
-    let dummy_wallet = String::from(DUMMY_WALLET);
-    let address = deps.api.addr_validate(dummy_wallet.clone().as_str())?;
-    let cpc = CONTRACT_POOL_COUNT.may_load(deps.storage, &address)?;
-    let global_pool_id;
-    match cpc {
-        Some(cpc) => {
-            global_pool_id = cpc;
-        }
-        None => {
-            global_pool_id = Uint128::zero();
-        }
+    let mut invitee_addrs = Vec::new();
+    for invitee in invitees.clone().unwrap_or_default() {
+        invitee_addrs.push(deps.api.addr_validate(&invitee)?);
     }
-    let mut count = global_pool_id;
-    CONTRACT_POOL_COUNT.update(
-        deps.storage,
-        &address,
-        |global_pool_id: Option<Uint128>| -> StdResult<_> {
-            Ok(global_pool_id.unwrap_or_default() + Uint128::from(1u128))
-        },
-    )?;
-    count += Uint128::from(1u128);
-    let pool_id_str: String = count.to_string();
+    let pool_id_str = save_new_pool(deps.branch(), game_id.clone(), pool_type.clone())?;
+    if invitees.is_some() || join_code_hash.is_some() {
+        POOL_ACCESS.save(
+            deps.storage,
+            (&*game_id, &*pool_id_str),
+            &PoolAccess {
+                creator: info.sender,
+                invitees: invitee_addrs,
+                join_code_hash,
+            },
+        )?;
+    }
+    return Ok(Response::new()
+        .add_attribute("pool_id", pool_id_str.clone())
+        .add_event(GamingPoolEvent::PoolCreated { game_id, pool_id: pool_id_str, pool_type }.into_event()));
+}
 
-    POOL_DETAILS.save(
+pub fn set_pool_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    pool_type: String,
+    min_pool_size: u32,
+    max_pool_size: u32,
+    creator_rake_percentage: u32,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    let pool_type_details = query_pool_type_details(deps.storage, pool_type.clone())?;
+    // Pools smaller than the pool type minimum would always be refunded at lock
+    if min_pool_size < pool_type_details.min_teams_for_pool || min_pool_size > max_pool_size {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Invalid pool size bounds for the pool type"),
+        }));
+    }
+    if creator_rake_percentage > 100 {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Creator rake percentage cannot be more than 100"),
+        }));
+    }
+    POOL_TEMPLATES.save(
         deps.storage,
-        pool_id_str.clone(),
-        &PoolDetails {
-            game_id: game_id.clone(),
-            pool_id: pool_id_str.clone(),
-            pool_type: pool_type.clone(),
-            current_teams_count: 0u32,
-            rewards_distributed: REWARDS_NOT_DISTRIBUTED,
-            pool_refund_status: false,
-            pool_reward_status: false,
+        pool_type.clone(),
+        &PoolTemplate {
+            min_pool_size,
+            max_pool_size,
+            creator_rake_percentage,
         },
     )?;
-    return Ok(Response::new().add_attribute("pool_id", pool_id_str.clone()));
+    return Ok(Response::new()
+        .add_attribute("action", "set_pool_template")
+        .add_attribute("pool_type", pool_type));
 }
 
-pub fn query_platform_fees(
-    pool_fee: Uint128,
-    platform_fees_percentage: Uint128,
-    transaction_fee_percentage: Uint128,
-) -> StdResult<FeeDetails> {
-    return Ok(FeeDetails {
+pub fn create_user_pool(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    pool_type: String,
+    name: String,
+    pool_size: u32,
+) -> Result<Response, ContractError> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.len() > MAX_POOL_NAME_LENGTH {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: format!("Pool name must be 1 to {} characters long", MAX_POOL_NAME_LENGTH),
+        }));
+    }
+    let template;
+    match POOL_TEMPLATES.may_load(deps.storage, pool_type.clone())? {
+        Some(pt) => {
+            template = pt;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Pool type is not open to player pools"),
+            }));
+        }
+    }
+    if pool_size < template.min_pool_size || pool_size > template.max_pool_size {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool size is out of the template bounds"),
+        }));
+    }
+    let game = query_game_details(deps.storage, &env.block, game_id.clone())?;
+    if game.game_status != GAME_POOL_OPEN {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Game is not open for bidding"),
+        }));
+    }
+    let pools_created = USER_POOLS_CREATED
+        .may_load(deps.storage, (&*game_id, info.sender.as_str()))?
+        .unwrap_or_default();
+    if pools_created >= MAX_USER_POOLS_PER_GAME {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Creator has opened the most pools allowed in this game"),
+        }));
+    }
+    USER_POOLS_CREATED.save(deps.storage, (&*game_id, info.sender.as_str()), &(pools_created + 1))?;
+
+    let pool_id_str = save_new_pool(deps.branch(), game_id.clone(), pool_type.clone())?;
+    USER_POOLS.save(
+        deps.storage,
+        (&*game_id, &*pool_id_str),
+        &UserPoolDetails {
+            creator: info.sender.clone(),
+            name: name.clone(),
+            max_teams_for_pool: pool_size,
+            creator_rake_percentage: template.creator_rake_percentage,
+        },
+    )?;
+    return Ok(Response::new()
+        .add_attribute("pool_id", pool_id_str.clone())
+        .add_attribute("creator", info.sender.to_string())
+        .add_attribute("name", name)
+        .add_event(GamingPoolEvent::PoolCreated { game_id, pool_id: pool_id_str, pool_type }.into_event()));
+}
+
+// Public pools let anyone in, private ones only invitees or gamers who know
+// the join code
+fn assert_pool_access(
+    storage: &dyn Storage,
+    game_id: &str,
+    pool_id: &str,
+    gamer: &str,
+    join_code: Option<String>,
+) -> Result<(), ContractError> {
+    let pool_access;
+    match POOL_ACCESS.may_load(storage, (game_id, pool_id))? {
+        Some(access) => {
+            pool_access = access;
+        }
+        None => return Ok(()),
+    }
+    if pool_access.invitees.iter().any(|invitee| invitee.as_str() == gamer) {
+        return Ok(());
+    }
+    match (join_code, pool_access.join_code_hash) {
+        (Some(join_code), Some(join_code_hash)) => {
+            if Sha256::digest(join_code.as_bytes()).as_slice() == join_code_hash.as_slice() {
+                return Ok(());
+            }
+        }
+        _ => {}
+    }
+    return Err(ContractError::Std(StdError::GenericErr {
+        msg: String::from("Pool is private and the gamer is not invited"),
+    }));
+}
+
+pub fn update_pool_invitees(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    pool_id: String,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let mut pool_access;
+    match POOL_ACCESS.may_load(deps.storage, (&*game_id, &*pool_id))? {
+        Some(access) => {
+            pool_access = access;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Pool is not private"),
+            }));
+        }
+    }
+    if info.sender != pool_access.creator {
+        return Err(ContractError::Unauthorized {
+            invoker: info.sender.to_string(),
+        });
+    }
+    let game = query_game_details(deps.storage, &env.block, game_id.clone())?;
+    if game.game_status != GAME_POOL_OPEN {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Invitees can only be changed while the game is open"),
+        }));
+    }
+    for invitee in add {
+        let invitee_addr = deps.api.addr_validate(&invitee)?;
+        if !pool_access.invitees.contains(&invitee_addr) {
+            pool_access.invitees.push(invitee_addr);
+        }
+    }
+    pool_access.invitees.retain(|invitee| !remove.contains(&invitee.to_string()));
+    POOL_ACCESS.save(deps.storage, (&*game_id, &*pool_id), &pool_access)?;
+    return Ok(Response::new()
+        .add_attribute("action", "update_pool_invitees")
+        .add_attribute("game_id", game_id)
+        .add_attribute("pool_id", pool_id)
+        .add_attribute("invitees", pool_access.invitees.len().to_string()));
+}
+
+fn save_new_pool(
+    deps: DepsMut,
+    game_id: String,
+    pool_type: String,
+) -> Result<String, ContractError> {
+    let pool_type_details;
+    match POOL_TYPE_DETAILS.may_load(deps.storage, pool_type.clone())? {
+        Some(ptd) => {
+            pool_type_details = ptd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("No pool type details found"),
+            }));
+        }
+    }
+    if pool_type_details.is_disabled {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool type is disabled"),
+        }));
+    }
+    let pools_in_game = pools()
+        .prefix(&game_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(MAX_POOLS_PER_GAME)
+        .count();
+    if pools_in_game >= MAX_POOLS_PER_GAME {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Game has the most pools allowed"),
+        }));
+    }
+    let dummy_wallet = String::from(DUMMY_WALLET);
+    let address = deps.api.addr_validate(dummy_wallet.clone().as_str())?;
+    let cpc = CONTRACT_POOL_COUNT.may_load(deps.storage, &address)?;
+    let global_pool_id;
+    match cpc {
+        Some(cpc) => {
+            global_pool_id = cpc;
+        }
+        None => {
+            global_pool_id = Uint128::zero();
+        }
+    }
+    let mut count = global_pool_id;
+    CONTRACT_POOL_COUNT.update(
+        deps.storage,
+        &address,
+        |global_pool_id: Option<Uint128>| -> StdResult<_> {
+            Ok(global_pool_id.unwrap_or_default() + Uint128::from(1u128))
+        },
+    )?;
+    count += Uint128::from(1u128);
+    let pool_id_str: String = count.to_string();
+
+    pools().save(
+        deps.storage,
+        (&*game_id, &*pool_id_str),
+        &PoolDetails {
+            game_id: game_id.clone(),
+            pool_id: pool_id_str.clone(),
+            pool_type: pool_type.clone(),
+            current_teams_count: 0u32,
+            rewards_distributed: REWARDS_NOT_DISTRIBUTED,
+            pool_refund_status: false,
+            pool_reward_status: false,
+        },
+    )?;
+    POOL_PARAMS.save(deps.storage, (&*game_id, &*pool_id_str), &pool_type_details)?;
+    return Ok(pool_id_str);
+}
+
+// Pick the lowest numbered pool of this type in the game that still has room for
+// the team, or open a new one if all of them are full. The pool created event
+// is returned when a new pool had to be opened
+fn find_or_create_open_pool(
+    deps: DepsMut,
+    game_id: String,
+    pool_type: String,
+    gamer: String,
+) -> Result<(String, Option<Event>), ContractError> {
+    let mut open_pool_id: Option<Uint128> = None;
+    let all_pools: Vec<PoolDetails> = pools()
+        .idx
+        .pool_type
+        .prefix((game_id.as_bytes().to_vec(), pool_type.as_bytes().to_vec()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|p| Ok(p?.1))
+        .collect::<StdResult<Vec<PoolDetails>>>()?;
+    for pool in all_pools {
+        let pool_type_details = query_pool_params(deps.storage, &game_id, &pool.pool_id)?;
+        if pool.current_teams_count >= pool_type_details.max_teams_for_pool {
+            continue;
+        }
+        // Private pools and pools created by players are only entered by their id
+        if POOL_ACCESS.has(deps.storage, (&game_id, &pool.pool_id))
+            || USER_POOLS.has(deps.storage, (&game_id, &pool.pool_id)) {
+            continue;
+        }
+        let gamer_team_count = pool_teams()
+            .may_load(deps.storage, (&game_id, &pool.pool_id, &gamer))?
+            .unwrap_or_default()
+            .len() as u32;
+        if gamer_team_count >= pool_type_details.max_teams_for_gamer {
+            continue;
+        }
+        let pool_id = Uint128::from_str(&pool.pool_id)?;
+        match open_pool_id {
+            Some(current) if current <= pool_id => {}
+            _ => {
+                open_pool_id = Some(pool_id);
+            }
+        }
+    }
+    match open_pool_id {
+        Some(pool_id) => return Ok((pool_id.to_string(), None)),
+        None => {
+            let pool_id = save_new_pool(deps, game_id.clone(), pool_type.clone())?;
+            let event = GamingPoolEvent::PoolCreated { game_id, pool_id: pool_id.clone(), pool_type }.into_event();
+            return Ok((pool_id, Some(event)));
+        }
+    }
+}
+
+pub fn query_platform_fees(
+    pool_fee: Uint128,
+    platform_fees_percentage: Uint128,
+    transaction_fee_percentage: Uint128,
+) -> StdResult<FeeDetails> {
+    return Ok(FeeDetails {
         platform_fee: Uint128::from(pool_fee
             .checked_mul(platform_fees_percentage)?
             .checked_div(Uint128::from(HUNDRED_PERCENT))?),
@@ -339,17 +921,117 @@ pub fn query_platform_fees(
     });
 }
 
+// Tiers must cover valid, non overlapping rank ranges and
+// together pay out no more than the whole prize pool
+pub fn validate_payout_tiers(payout_tiers: &Vec<PayoutTier>) -> Result<(), ContractError> {
+    let mut total_percentage = 0u128;
+    for (i, tier) in payout_tiers.iter().enumerate() {
+        if tier.from_rank == 0 || tier.from_rank > tier.to_rank {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Invalid rank range in payout tier"),
+            }));
+        }
+        for other in payout_tiers.iter().skip(i + 1) {
+            if tier.from_rank <= other.to_rank && other.from_rank <= tier.to_rank {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: String::from("Payout tiers overlap"),
+                }));
+            }
+        }
+        let ranks_in_tier = u128::from(tier.to_rank - tier.from_rank + 1);
+        total_percentage = ranks_in_tier
+            .checked_mul(u128::from(tier.percentage))
+            .and_then(|tier_total| total_percentage.checked_add(tier_total))
+            .unwrap_or(u128::MAX);
+    }
+    if total_percentage > HUNDRED_PERCENT {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Payout tiers add up to more than 100 percent"),
+        }));
+    }
+    return Ok(());
+}
+
+fn rank_percentage(payout_tiers: &Vec<PayoutTier>, rank: u64) -> u128 {
+    for tier in payout_tiers {
+        if tier.from_rank <= rank && rank <= tier.to_rank {
+            return u128::from(tier.percentage);
+        }
+    }
+    return 0u128;
+}
+
+// Teams tied at a rank take up as many ranks as there are of them, so two
+// teams tied at rank 2 hold ranks 2 and 3
+pub fn occupied_ranks(game_winners: &Vec<TeamRanking>) -> Result<Vec<u64>, ContractError> {
+    let mut group_ranks: Vec<u64> = Vec::new();
+    let mut occupied: Vec<u64> = Vec::new();
+    for winner in game_winners {
+        if group_ranks.contains(&winner.team_rank) {
+            continue;
+        }
+        group_ranks.push(winner.team_rank);
+        let tied_teams = game_winners
+            .iter()
+            .filter(|w| w.team_rank == winner.team_rank)
+            .count() as u64;
+        for rank in winner.team_rank..winner.team_rank.saturating_add(tied_teams) {
+            if occupied.contains(&rank) {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: String::from("Rank is taken by tied teams of the batch"),
+                }));
+            }
+            occupied.push(rank);
+        }
+    }
+    return Ok(occupied);
+}
+
+// Reward for each team in the batch, in the same order as game_winners.
+// Teams tied at a rank take up that many ranks and split their shares equally,
+// so all tied teams have to be submitted in the same batch.
+pub fn compute_team_rewards(
+    payout_tiers: &Vec<PayoutTier>,
+    game_winners: &Vec<TeamRanking>,
+    prize_pool: Uint128,
+    ranks_assigned: &Vec<u64>,
+) -> Result<Vec<Uint128>, ContractError> {
+    for rank in occupied_ranks(game_winners)? {
+        if ranks_assigned.contains(&rank) {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Rank already assigned in an earlier batch"),
+            }));
+        }
+    }
+    let mut rewards = Vec::new();
+    for winner in game_winners {
+        let tied_teams = game_winners
+            .iter()
+            .filter(|w| w.team_rank == winner.team_rank)
+            .count() as u64;
+        let mut shares = 0u128;
+        for rank in winner.team_rank..winner.team_rank.saturating_add(tied_teams) {
+            shares += rank_percentage(payout_tiers, rank);
+        }
+        let reward = prize_pool.multiply_ratio(shares, HUNDRED_PERCENT * u128::from(tied_teams));
+        rewards.push(reward);
+    }
+    return Ok(rewards);
+}
+
 pub fn game_pool_bid_submit(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     gamer: String,
+    game_id: String,
     pool_type: String,
     pool_id: String,
     team_id: String,
     amount: Uint128,
-    testing: bool,
+    oracle: &dyn PriceOracle,
     max_spread: Option<Decimal>,
+    join_code: Option<String>,
 ) -> Result<Response, ContractError> {
     //Check if gamer is same as invoker
     if gamer != info.sender {
@@ -357,33 +1039,499 @@ pub fn game_pool_bid_submit(
             invoker: info.sender.to_string(),
         });
     }
+    assert_pool_access(deps.storage, &game_id, &pool_id, &gamer, join_code)?;
 
     let config = CONFIG.load(deps.storage)?;
     // Calculate
     let platform_fee = config.platform_fee; //  Should be in %
-    let game_id = config.clone().game_id;
     let mut messages = Vec::new(); //  Use this to append any execute messaages in the funciton
-    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
-    let game;
-    match gd {
-        Some(gd) => {
-            game = gd;
+
+    let pool_type_details;
+    let ptd = may_load_pool_params(deps.storage, &game_id, &pool_id)?;
+    match ptd {
+        Some(ptd) => {
+            pool_type_details = ptd;
         }
         None => {
             return Err(ContractError::Std(StdError::GenericErr {
-                msg: String::from("Game status cannot be retrieved"),
+                msg: String::from("Cant get details for pool type "),
             }));
         }
     }
-    if game.game_status != GAME_POOL_OPEN {
+    // Pools with their own entry asset take it as it is, no swap needed
+    match pool_type_details.entry_asset.clone() {
+        Some(entry_asset) => {
+            return game_pool_bid_submit_in_asset(
+                deps, env, info, gamer, game_id, pool_type, pool_id, team_id, amount, entry_asset, oracle,
+            );
+        }
+        None => {}
+    }
+
+    let fee_details = query_platform_fees(
+        pool_type_details.pool_fee,
+        platform_fee,
+        config.transaction_fee,
+    )?;
+    let required_platform_fee_ust = fee_details.platform_fee;
+    let transaction_fee = fee_details.transaction_fee;
+
+    if info.funds.clone().len() != 1 {
+        return Err(ContractError::InvalidNumberOfCoinsSent {});
+    }
+    let fund = info.funds.clone();
+    if fund[0].denom != "uusd" || fund[0].amount < required_platform_fee_ust.add(transaction_fee) {
+        return Err(ContractError::InsufficientFeesUst {});
+    }
+
+    let (pool_id_return, event) = record_pool_bid(
+        deps.branch(),
+        env.clone(),
+        gamer.clone(),
+        game_id,
+        pool_type,
+        pool_id,
+        team_id,
+        amount,
+        oracle,
+    )?;
+
+    // Sending Fury token to the contract
+    let transfer_msg = Cw20ExecuteMsg::TransferFrom {
+        owner: info.sender.into_string(),
+        recipient: env.clone().contract.address.to_string(),
+        amount,
+    };
+    let exec = WasmMsg::Execute {
+        contract_addr: config.minting_contract_address.to_string(),
+        msg: to_binary(&transfer_msg).unwrap(),
+        funds: vec![],
+    };
+    messages.push(CosmosMsg::Wasm(exec));
+
+    let mut swap_messages = bid_swap_messages(deps.as_ref(), env, amount, oracle, max_spread)?;
+    messages.append(&mut swap_messages);
+    return Ok(Response::new()
+        .add_attribute("pool_id", pool_id_return.clone())
+        .add_event(event)
+        .add_messages(messages));
+}
+
+pub fn game_pool_bid_submit_any_pool(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    gamer: String,
+    game_id: String,
+    pool_type: String,
+    team_id: String,
+    amount: Uint128,
+    oracle: &dyn PriceOracle,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    if gamer != info.sender {
+        return Err(ContractError::Unauthorized {
+            invoker: info.sender.to_string(),
+        });
+    }
+    let (pool_id, pool_created) = find_or_create_open_pool(deps.branch(), game_id.clone(), pool_type.clone(), gamer.clone())?;
+    let mut rsp = game_pool_bid_submit(
+        deps,
+        env,
+        info,
+        gamer,
+        game_id.clone(),
+        pool_type,
+        pool_id.clone(),
+        team_id,
+        amount,
+        oracle,
+        max_spread,
+        None,
+    )?;
+    if let Some(event) = pool_created {
+        rsp = rsp.add_event(event);
+    }
+    return Ok(rsp.set_data(to_binary(&BidSubmitResponse { game_id, pool_id })?));
+}
+
+fn game_pool_bid_submit_in_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    gamer: String,
+    game_id: String,
+    pool_type: String,
+    pool_id: String,
+    team_id: String,
+    amount: Uint128,
+    entry_asset: Denom,
+    oracle: &dyn PriceOracle,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut messages = Vec::new();
+    match entry_asset.clone() {
+        Denom::Native(denom) => {
+            if info.funds.len() != 1 {
+                return Err(ContractError::InvalidNumberOfCoinsSent {});
+            }
+            if info.funds[0].denom != denom || info.funds[0].amount != amount {
+                return Err(ContractError::InvalidEntryFunds {});
+            }
+        }
+        Denom::Cw20(token) => {
+            let transfer_msg = Cw20ExecuteMsg::TransferFrom {
+                owner: info.sender.to_string(),
+                recipient: env.contract.address.to_string(),
+                amount,
+            };
+            messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_binary(&transfer_msg)?,
+                funds: vec![],
+            }));
+        }
+    }
+    let (pool_id_return, fee, event) = record_asset_bid(
+        deps, env, gamer.clone(), game_id, pool_type, pool_id, team_id, amount, oracle,
+    )?;
+    if !fee.is_zero() {
+        messages.push(asset_transfer_msg(&entry_asset, config.platform_fees_collector_wallet.to_string(), fee)?);
+    }
+    return Ok(Response::new()
+        .add_attribute("pool_id", pool_id_return.clone())
+        .add_attribute("gamer", gamer)
+        .add_attribute("fee", fee.to_string())
+        .add_event(event)
+        .add_messages(messages));
+}
+
+// The amount must be exactly the pool fee plus the platform and transaction
+// fees, both taken in the entry asset. Returns the pool and the fee to forward
+fn record_asset_bid(
+    deps: DepsMut,
+    env: Env,
+    gamer: String,
+    game_id: String,
+    pool_type: String,
+    pool_id: String,
+    team_id: String,
+    amount: Uint128,
+    oracle: &dyn PriceOracle,
+) -> Result<(String, Uint128, Event), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let pool_type_details = query_pool_params(deps.storage, &game_id, &pool_id)?;
+    let fee_details = query_platform_fees(
+        pool_type_details.pool_fee,
+        config.platform_fee,
+        config.transaction_fee,
+    )?;
+    let fee = fee_details.platform_fee.add(fee_details.transaction_fee);
+    if amount != pool_type_details.pool_fee.add(fee) {
+        return Err(ContractError::InvalidEntryFunds {});
+    }
+    let (pool_id_return, event) = record_pool_bid(
+        deps,
+        env,
+        gamer,
+        game_id,
+        pool_type,
+        pool_id,
+        team_id,
+        pool_type_details.pool_fee,
+        oracle,
+    )?;
+    return Ok((pool_id_return, fee, event));
+}
+
+// Cw20 token a bid for the pool has to be sent with, taken from the pool's
+// own snapshot of its pool type
+fn entry_cw20_token(
+    storage: &dyn Storage,
+    config: &Config,
+    game_id: &str,
+    pool_id: &str,
+    pool_type: &str,
+) -> Result<Addr, ContractError> {
+    let pool_details = query_pool_details(storage, game_id.to_string(), pool_id.to_string())?;
+    if pool_details.pool_type != pool_type {
         return Err(ContractError::Std(StdError::GenericErr {
-            msg: String::from("Game is not open for bidding"),
+            msg: String::from("Pool type does not match the pool"),
         }));
     }
+    let entry_asset = query_pool_params(storage, game_id, pool_id)?.entry_asset;
+    match entry_asset {
+        None => return Ok(config.minting_contract_address.clone()),
+        Some(Denom::Cw20(token)) => return Ok(token),
+        Some(Denom::Native(_)) => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Pool type takes a native coin as entry fee"),
+            }));
+        }
+    }
+}
+
+pub fn asset_transfer_msg(asset: &Denom, recipient: String, amount: Uint128) -> StdResult<CosmosMsg> {
+    match asset {
+        Denom::Native(denom) => {
+            return Ok(CosmosMsg::Bank(BankMsg::Send {
+                to_address: recipient,
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount,
+                }],
+            }));
+        }
+        Denom::Cw20(token) => {
+            return Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer { recipient, amount })?,
+                funds: vec![],
+            }));
+        }
+    }
+}
+
+// Orders ranked teams by rank, then by points from the highest
+pub fn leaderboard_key(team: &PoolTeamDetails) -> Vec<u8> {
+    let mut key = team.team_rank.to_be_bytes().to_vec();
+    key.extend_from_slice(&(u64::MAX - team.team_points).to_be_bytes());
+    key.extend_from_slice(team.gamer_address.as_bytes());
+    key.push(0u8);
+    key.extend_from_slice(team.team_id.as_bytes());
+    return key;
+}
+
+pub fn add_asset_amount(totals: &mut Vec<(Denom, Uint128)>, asset: Denom, amount: Uint128) {
+    for total in totals.iter_mut() {
+        if total.0 == asset {
+            total.1 += amount;
+            return;
+        }
+    }
+    totals.push((asset, amount));
+}
+
+// Pools without a ledger yet start from an empty one
+fn update_pool_ledger<A>(storage: &mut dyn Storage, game_id: &str, pool_id: &str, action: A) -> StdResult<PoolLedger>
+    where A: FnOnce(&mut PoolLedger) {
+    track_pool_obligations(storage, game_id, pool_id, |storage| {
+        POOL_LEDGERS.update(storage, (game_id, pool_id), |ledger: Option<PoolLedger>| -> StdResult<_> {
+            let mut ledger = ledger.unwrap_or_default();
+            action(&mut ledger);
+            Ok(ledger)
+        })
+    })
+}
+
+// Runs a change to the pool and moves the running obligation totals by
+// what the pool owes after it compared to before it
+fn track_pool_obligations<T, A>(storage: &mut dyn Storage, game_id: &str, pool_id: &str, action: A) -> StdResult<T>
+    where A: FnOnce(&mut dyn Storage) -> StdResult<T> {
+    let pool = query_pool_details(storage, game_id.to_string(), pool_id.to_string())?;
+    let owed_before = pool_obligations(storage, &pool)?;
+    let result = action(storage)?;
+    let pool = query_pool_details(storage, game_id.to_string(), pool_id.to_string())?;
+    let owed_after = pool_obligations(storage, &pool)?;
+
+    let mut totals = POOL_OBLIGATIONS.may_load(storage)?.unwrap_or_default();
+    for (asset, owed) in owed_after {
+        add_asset_amount(&mut totals, asset, owed);
+    }
+    for (asset, owed) in owed_before {
+        for total in totals.iter_mut() {
+            if total.0 == asset {
+                total.1 = total.1.checked_sub(owed).unwrap_or_default();
+            }
+        }
+    }
+    POOL_OBLIGATIONS.save(storage, &totals)?;
+    return Ok(result);
+}
+
+// Stakes, refunds and winnings of pools paid in FURY are counted in UST, like the ledger
+fn stake_asset(pool_type_details: &PoolTypeDetails) -> Denom {
+    match pool_type_details.entry_asset.clone() {
+        Some(asset) => asset,
+        None => Denom::Native("uusd".to_string()),
+    }
+}
+
+// Every gamer in a pool set to refund gets it listed for their refund claims
+fn credit_pool_refunds(storage: &mut dyn Storage, game_id: &str, pool_id: &str) -> StdResult<()> {
+    let gamers = pool_teams()
+        .prefix((game_id, pool_id))
+        .keys(storage, None, None, Order::Ascending)
+        .map(|k| String::from_utf8(k).map_err(StdError::invalid_utf8))
+        .collect::<StdResult<Vec<String>>>()?;
+    for gamer in gamers {
+        GAMER_REFUNDABLE_POOLS.save(storage, (&*gamer, pool_id), &game_id.to_string())?;
+    }
+    return Ok(());
+}
+
+// Pools given by the gamer must be listed in the index, otherwise the index is
+// gone through in order. At most limit pools are returned as (game id, pool id)
+fn claimable_pools(
+    storage: &dyn Storage,
+    index: &Map<(&str, &str), String>,
+    gamer: &str,
+    pool_ids: Option<Vec<String>>,
+    limit: usize,
+) -> Result<Vec<(String, String)>, ContractError> {
+    let mut claimable = Vec::new();
+    match pool_ids {
+        Some(pool_ids) => {
+            for pool_id in pool_ids.into_iter().take(limit) {
+                let game_id = index.may_load(storage, (gamer, &*pool_id))?;
+                match game_id {
+                    Some(game_id) => {
+                        claimable.push((game_id, pool_id));
+                    }
+                    None => {
+                        return Err(ContractError::Std(StdError::GenericErr {
+                            msg: String::from("Nothing to claim for this user in the pool"),
+                        }));
+                    }
+                }
+            }
+        }
+        None => {
+            let listed_pools = index
+                .prefix(gamer)
+                .range(storage, None, None, Order::Ascending)
+                .take(limit)
+                .collect::<StdResult<Vec<(Vec<u8>, String)>>>()?;
+            for (pool_id, game_id) in listed_pools {
+                claimable.push((game_id, String::from_utf8(pool_id).map_err(StdError::invalid_utf8)?));
+            }
+        }
+    }
+    return Ok(claimable);
+}
+
+fn update_gamer_stats<A>(storage: &mut dyn Storage, gamer: &str, asset: Denom, action: A) -> StdResult<GamerStats>
+    where A: FnOnce(&mut GamerStats, &mut GamerAssetStats) {
+    GAMER_STATS.update(storage, gamer, |stats: Option<GamerStats>| -> StdResult<_> {
+        let mut stats = stats.unwrap_or_default();
+        let mut asset_stats = GamerAssetStats {
+            asset: asset.clone(),
+            spent: Uint128::zero(),
+            won: Uint128::zero(),
+            refunded: Uint128::zero(),
+        };
+        let position = stats.assets.iter().position(|a| a.asset == asset);
+        match position {
+            Some(index) => {
+                asset_stats = stats.assets.remove(index);
+            }
+            None => {}
+        }
+        action(&mut stats, &mut asset_stats);
+        match position {
+            Some(index) => stats.assets.insert(index, asset_stats),
+            None => stats.assets.push(asset_stats),
+        }
+        Ok(stats)
+    })
+}
+
+pub fn received_message(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    message: Cw20ReceiveMsg,
+    oracle: &dyn PriceOracle,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let msg: ReceivedMsg = from_binary(&message.msg)?;
+    // Bids are paid in FURY unless the pool takes another token
+    match msg {
+        ReceivedMsg::GamePoolBidSubmit(gpbsc) => {
+            let token = entry_cw20_token(deps.storage, &config, &gpbsc.game_id, &gpbsc.pool_id, &gpbsc.pool_type)?;
+            if info.sender != token {
+                return Err(ContractError::Unauthorized {
+                    invoker: info.sender.to_string(),
+                });
+            }
+            game_pool_bid_submit_from_cw20(
+                deps,
+                env,
+                message.sender,
+                gpbsc,
+                message.amount,
+                oracle,
+            )
+        }
+        ReceivedMsg::GamePoolBidSubmitAnyPool(gpbsapc) => {
+            if message.sender != gpbsapc.gamer {
+                return Err(ContractError::Unauthorized {
+                    invoker: message.sender.to_string(),
+                });
+            }
+            let (pool_id, pool_created) = find_or_create_open_pool(
+                deps.branch(),
+                gpbsapc.game_id.clone(),
+                gpbsapc.pool_type.clone(),
+                gpbsapc.gamer.clone(),
+            )?;
+            let token = entry_cw20_token(deps.storage, &config, &gpbsapc.game_id, &pool_id, &gpbsapc.pool_type)?;
+            if info.sender != token {
+                return Err(ContractError::Unauthorized {
+                    invoker: info.sender.to_string(),
+                });
+            }
+            let mut rsp = game_pool_bid_submit_from_cw20(
+                deps,
+                env,
+                message.sender,
+                GamePoolBidSubmitCommand {
+                    gamer: gpbsapc.gamer,
+                    game_id: gpbsapc.game_id.clone(),
+                    pool_type: gpbsapc.pool_type,
+                    pool_id: pool_id.clone(),
+                    team_id: gpbsapc.team_id,
+                    max_spread: gpbsapc.max_spread,
+                    join_code: None,
+                },
+                message.amount,
+                oracle,
+            )?;
+            if let Some(event) = pool_created {
+                rsp = rsp.add_event(event);
+            }
+            Ok(rsp.set_data(to_binary(&BidSubmitResponse { game_id: gpbsapc.game_id, pool_id })?))
+        }
+    }
+}
+
+// Bid paid with a single Cw20 Send. The FURY is already in the contract
+// and no native coins can come along with it, so the platform and
+// transaction fee is taken out of the FURY sent and passed on to the
+// platform fee collector. The rest is swapped exactly like a regular bid.
+pub fn game_pool_bid_submit_from_cw20(
+    mut deps: DepsMut,
+    env: Env,
+    cw20_sender: String,
+    command: GamePoolBidSubmitCommand,
+    amount: Uint128,
+    oracle: &dyn PriceOracle,
+) -> Result<Response, ContractError> {
+    let gamer_addr = deps.api.addr_validate(&cw20_sender)?;
+    //Check if gamer is same as the one who sent the tokens
+    if command.gamer != gamer_addr {
+        return Err(ContractError::Unauthorized {
+            invoker: cw20_sender,
+        });
+    }
+    assert_pool_access(deps.storage, &command.game_id, &command.pool_id, &command.gamer, command.join_code.clone())?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let mut messages = Vec::new();
 
     let pool_type_details;
-    let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool_type.clone())?;
-    match ptd.clone() {
+    let ptd = may_load_pool_params(deps.storage, &command.game_id, &command.pool_id)?;
+    match ptd {
         Some(ptd) => {
             pool_type_details = ptd;
         }
@@ -393,60 +1541,131 @@ pub fn game_pool_bid_submit(
             }));
         }
     }
-    let required_platform_fee_ust;
-    let transaction_fee;
-    match testing {
-        true => {
-            required_platform_fee_ust = Uint128::zero();
-            transaction_fee = Uint128::zero();
-        }
-        false => {
-            let fee_details = query_platform_fees(
-                pool_type_details.pool_fee,
-                platform_fee,
-                config.transaction_fee,
+    match pool_type_details.entry_asset.clone() {
+        Some(entry_asset) => {
+            let (pool_id_return, fee, event) = record_asset_bid(
+                deps,
+                env,
+                command.gamer.clone(),
+                command.game_id,
+                command.pool_type,
+                command.pool_id,
+                command.team_id,
+                amount,
+                oracle,
             )?;
-            required_platform_fee_ust = fee_details.platform_fee;
-            transaction_fee = fee_details.transaction_fee;
+            if !fee.is_zero() {
+                messages.push(asset_transfer_msg(&entry_asset, config.platform_fees_collector_wallet.to_string(), fee)?);
+            }
+            return Ok(Response::new()
+                .add_attribute("pool_id", pool_id_return.clone())
+                .add_attribute("gamer", command.gamer)
+                .add_attribute("fee", fee.to_string())
+                .add_event(event)
+                .add_messages(messages));
         }
+        None => {}
     }
+    let fee_details = query_platform_fees(
+        pool_type_details.pool_fee,
+        config.platform_fee,
+        config.transaction_fee,
+    )?;
+    let fee_in_fury = oracle.fury_equivalent_to_ust(fee_details.platform_fee.add(fee_details.transaction_fee))?;
+    if amount <= fee_in_fury {
+        return Err(ContractError::InsufficientFeesFury {});
+    }
+    let amount_for_pool = amount - fee_in_fury;
+
+    let (pool_id_return, event) = record_pool_bid(
+        deps.branch(),
+        env.clone(),
+        command.gamer.clone(),
+        command.game_id,
+        command.pool_type,
+        command.pool_id,
+        command.team_id,
+        amount_for_pool,
+        oracle,
+    )?;
 
-    if !testing {
-        if info.funds.clone().len() != 1 {
-            return Err(ContractError::InvalidNumberOfCoinsSent {});
-        }
-        let mut asset: Asset = Asset {
-            info: AssetInfo::NativeToken { denom: info.funds[0].denom.clone() },
-            amount: info.funds[0].amount,
+    if !fee_in_fury.is_zero() {
+        let transfer_msg = Cw20ExecuteMsg::Transfer {
+            recipient: config.platform_fees_collector_wallet.to_string(),
+            amount: fee_in_fury,
         };
-        let fund = info.funds.clone();
-        if fund[0].denom == "uusd" {
-            if fund[0].amount >= required_platform_fee_ust.add(transaction_fee) {
-                asset = Asset {
-                    info: AssetInfo::NativeToken { denom: fund[0].denom.clone() },
-                    amount: fund[0].amount,
-                };
-                println!("Asset {}", asset);
-            } else {
-                return Err(ContractError::InsufficientFeesUst {});
-            }
-        } else {
-            return Err(ContractError::InsufficientFeesUst {});
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.minting_contract_address.to_string(),
+            msg: to_binary(&transfer_msg)?,
+            funds: vec![],
+        }));
+    }
+
+    let mut swap_messages = bid_swap_messages(deps.as_ref(), env, amount_for_pool, oracle, command.max_spread)?;
+    messages.append(&mut swap_messages);
+    return Ok(Response::new()
+        .add_attribute("pool_id", pool_id_return.clone())
+        .add_attribute("gamer", command.gamer)
+        .add_attribute("fee_in_fury", fee_in_fury.to_string())
+        .add_event(event)
+        .add_messages(messages));
+}
+
+// Checks the bid against the game, the pool type and the pool,
+// then records the team in the pool. Returns the pool id the team was added to.
+fn record_pool_bid(
+    deps: DepsMut,
+    env: Env,
+    gamer: String,
+    game_id: String,
+    pool_type: String,
+    pool_id: String,
+    team_id: String,
+    amount: Uint128,
+    oracle: &dyn PriceOracle,
+) -> Result<(String, Event), ContractError> {
+    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    let game;
+    match gd {
+        Some(gd) => {
+            game = gd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Game status cannot be retrieved"),
+            }));
         }
-        println!("Asset {}", asset);
+    }
+    if get_game_status(&game, &env.block) != GAME_POOL_OPEN {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Game is not open for bidding"),
+        }));
+    }
+    let mut pool_details = query_pool_details(deps.storage, game_id.clone(), pool_id.clone())?;
+    if pool_details.pool_type != pool_type {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool type does not match the pool"),
+        }));
     }
 
+    let pool_type_details;
+    let ptd = may_load_pool_params(deps.storage, &game_id, &pool_id)?;
+    match ptd.clone() {
+        Some(ptd) => {
+            pool_type_details = ptd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Cant get details for pool type "),
+            }));
+        }
+    }
 
     let mut pool_fee: Uint128 = pool_type_details.pool_fee;
-    if !testing {
-        pool_fee = deps.querier.query_wasm_smart(
-            config.clone().astro_proxy_address,
-            &ProxyQueryMsgs::get_fury_equivalent_to_ust {
-                ust_count: pool_type_details.pool_fee,
-            },
-        )?;
+    if pool_type_details.entry_asset.is_none() {
+        pool_fee = oracle.fury_equivalent_to_ust(pool_type_details.pool_fee)?;
     }
-    let max_teams_for_pool = pool_type_details.max_teams_for_pool;
+    let max_teams_for_pool = query_max_teams_for_pool(deps.storage, &game_id, &pool_id, &pool_type_details)?;
     let max_teams_for_gamer = pool_type_details.max_teams_for_gamer;
     let amount_required = pool_fee
         * (Uint128::from(NINETY_NINE_NINE_PERCENT))
@@ -458,7 +1677,7 @@ pub fn game_pool_bid_submit(
     }
     let mut user_team_count = 0;
     // Here we load the details based on the user placing the bid
-    let ptd = POOL_TEAM_DETAILS.may_load(deps.storage, (&pool_id.clone(), &gamer))?;
+    let ptd = pool_teams().may_load(deps.storage, (&game_id, &pool_id.clone(), &gamer))?;
     match ptd {
         Some(std) => {
             let all_teams = std;
@@ -477,17 +1696,16 @@ pub fn game_pool_bid_submit(
     }
 
     let pool_id_return;
-    let mut pool_details = query_pool_details(deps.storage, pool_id.clone())?;
 
     // check if the pool can accomodate the team
     if pool_details.current_teams_count < max_teams_for_pool {
         pool_id_return = pool_id.clone();
         pool_details.current_teams_count += 1;
-        POOL_DETAILS.save(
+        pools().save(
             deps.storage,
-            pool_id.clone(),
+            (&*game_id, &*pool_id),
             &PoolDetails {
-                pool_type: pool_type.clone(),
+                pool_type: pool_details.pool_type.clone(),
                 pool_id: pool_id.clone(),
                 game_id: pool_details.game_id.clone(),
                 current_teams_count: pool_details.current_teams_count,
@@ -512,26 +1730,41 @@ pub fn game_pool_bid_submit(
             INITIAL_TEAM_POINTS,
             INITIAL_TEAM_RANK,
         )?;
+        update_pool_ledger(deps.storage, &game_id, &pool_id, |ledger| {
+            ledger.collected += pool_type_details.pool_fee;
+        })?;
+        update_gamer_stats(deps.storage, &gamer, stake_asset(&pool_type_details), |stats, asset_stats| {
+            if user_team_count == 0 {
+                stats.pools_entered += 1;
+            }
+            stats.teams_entered += 1;
+            asset_stats.spent += pool_type_details.pool_fee;
+        })?;
     } else {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("pool max team limit reached "),
-        }));
-    }
-
-    // Sending Fury token to the contract
-    let transfer_msg = Cw20ExecuteMsg::TransferFrom {
-        owner: info.sender.into_string(),
-        recipient: env.clone().contract.address.to_string(),
-        amount,
-    };
-    let exec = WasmMsg::Execute {
-        contract_addr: config.minting_contract_address.to_string(),
-        msg: to_binary(&transfer_msg).unwrap(),
-        funds: vec![],
-    };
-    messages.push(CosmosMsg::Wasm(exec));
-
+        }));
+    }
+    let event = GamingPoolEvent::TeamEntered {
+        game_id,
+        pool_id: pool_id_return.clone(),
+        gamer,
+        team_id,
+        amount,
+    }.into_event();
+    return Ok((pool_id_return, event));
+}
 
+// Messages to swap the FURY received for a bid through the proxy
+fn bid_swap_messages(
+    deps: Deps,
+    env: Env,
+    amount: Uint128,
+    oracle: &dyn PriceOracle,
+    max_spread: Option<Decimal>,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut messages = Vec::new();
     let increase_allowance_msg = Cw20ExecuteMsg::IncreaseAllowance {
         spender: String::from(config.clone().astro_proxy_address),
         amount,
@@ -556,12 +1789,7 @@ pub fn game_pool_bid_submit(
         max_spread: max_spread,
         to: Option::from(env.contract.address.to_string()),
     };
-    let platform_fees_for_swap = deps.querier.query_wasm_smart(
-        config.clone().astro_proxy_address,
-        &QueryMsgSimulation::QueryPlatformFees {
-            msg: to_binary(&swap_message)?
-        },
-    )?;
+    let platform_fees_for_swap = oracle.swap_fee(&swap_message)?;
     messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: config.clone().astro_proxy_address.to_string(),
         msg: to_binary(&swap_message).unwrap(),
@@ -570,9 +1798,7 @@ pub fn game_pool_bid_submit(
             amount: platform_fees_for_swap,
         }],
     }));
-    return Ok(Response::new()
-        .add_attribute("pool_id", pool_id_return.clone())
-        .add_messages(messages));
+    return Ok(messages);
 }
 
 pub fn save_team_details(
@@ -592,7 +1818,7 @@ pub fn save_team_details(
 ) -> Result<Response, ContractError> {
     // Get the existing teams for this pool
     let mut teams = Vec::new();
-    let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&pool_id.clone(), gamer.clone().as_ref()))?;
+    let all_teams = pool_teams().may_load(storage, (&game_id.clone(), &pool_id.clone(), gamer.clone().as_ref()))?;
     match all_teams {
         Some(some_teams) => {
             teams = some_teams;
@@ -612,12 +1838,68 @@ pub fn save_team_details(
         claimed_refund,
         team_points,
         team_rank,
+        team_changes: vec![],
     });
-    POOL_TEAM_DETAILS.save(storage, (&pool_id.clone(), gamer.as_ref()), &teams)?;
+    pool_teams().save(storage, (&game_id.clone(), &pool_id.clone(), gamer.as_ref()), &teams)?;
 
     return Ok(Response::new().add_attribute("team_id", team_id.clone()));
 }
 
+// Settings replace the previous ones, a field left out is cleared
+pub fn set_claim_settings(
+    deps: DepsMut,
+    info: MessageInfo,
+    payout_address: Option<String>,
+    relayer: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut settings = ClaimSettings::default();
+    match payout_address {
+        Some(payout_address) => {
+            settings.payout_address = Some(deps.api.addr_validate(&payout_address)?);
+        }
+        None => {}
+    }
+    match relayer {
+        Some(relayer) => {
+            settings.relayer = Some(deps.api.addr_validate(&relayer)?);
+        }
+        None => {}
+    }
+    CLAIM_SETTINGS.save(deps.storage, info.sender.as_str(), &settings)?;
+    let mut rsp = Response::new()
+        .add_attribute("action", "set_claim_settings")
+        .add_attribute("gamer", info.sender.to_string());
+    match settings.payout_address {
+        Some(payout_address) => {
+            rsp = rsp.add_attribute("payout_address", payout_address.to_string());
+        }
+        None => {}
+    }
+    match settings.relayer {
+        Some(relayer) => {
+            rsp = rsp.add_attribute("relayer", relayer.to_string());
+        }
+        None => {}
+    }
+    return Ok(rsp);
+}
+
+// The gamer or their relayer can claim, the tokens go to the payout address
+// of the gamer when one is registered
+fn claim_recipient(deps: Deps, info: &MessageInfo, gamer: &str) -> Result<String, ContractError> {
+    let gamer_addr = deps.api.addr_validate(gamer)?;
+    let settings = CLAIM_SETTINGS.may_load(deps.storage, gamer)?.unwrap_or_default();
+    if gamer_addr != info.sender && settings.relayer != Some(info.sender.clone()) {
+        return Err(ContractError::Unauthorized {
+            invoker: info.sender.to_string(),
+        });
+    }
+    match settings.payout_address {
+        Some(payout_address) => return Ok(payout_address.to_string()),
+        None => return Ok(gamer_addr.to_string()),
+    }
+}
+
 // Reward:Platform fee has to charged. Reward amount here is in FURY.
 // Make a call to astroport to get the platform fee, that is to be charged.
 // Here we only transfer the FURY and here since the amount is in
@@ -626,47 +1908,53 @@ pub fn claim_reward(
     deps: DepsMut,
     info: MessageInfo,
     gamer: String,
-    env: Env,
+    oracle: &dyn PriceOracle,
+    pool_ids: Option<Vec<String>>,
+    limit: Option<u32>,
+    auto_stake_club: Option<String>,
 ) -> Result<Response, ContractError> {
-    let gamer_addr = deps.api.addr_validate(&gamer)?;
-    //Check if withdrawer is same as invoker
-    if gamer_addr != info.sender {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
-    }
+    let recipient = claim_recipient(deps.as_ref(), &info, &gamer)?;
 
     let mut user_reward = Uint128::zero();
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        // Get the existing teams for this pool
-        let mut pool_details: PoolDetails = Default::default();
-        let pd = POOL_DETAILS.load(deps.storage, pool_id.clone());
-        match pd {
-            Ok(some) => { pool_details = some; }
-            Err(_) => {
-                continue;
+    let mut events: Vec<Event> = Vec::new();
+    // Rewards of pools with their own entry asset are paid out in that asset
+    let mut asset_rewards: Vec<(Denom, Uint128)> = Vec::new();
+    let limit = limit.unwrap_or(DEFAULT_CLAIM_LIMIT).min(MAX_CLAIM_LIMIT) as usize;
+    let mut pools_claimed = 0;
+    for (game_id, pool_id) in claimable_pools(deps.storage, &GAMER_CLAIMABLE_POOLS, &gamer, pool_ids, limit)? {
+        // Whatever is left is claimed now. A later batch lists the pool again
+        pools_claimed += 1;
+        GAMER_CLAIMABLE_POOLS.remove(deps.storage, (&*gamer, &*pool_id));
+        let pool_team_details;
+        let ptd = pool_teams().may_load(deps.storage, (&*game_id, &*pool_id, &*gamer))?;
+        match ptd {
+            Some(ptd) => {
+                pool_team_details = ptd;
             }
-        }
-        if !pool_details.pool_reward_status {
-            continue;
-        }
-        let mut pool_team_details;
-        match POOL_TEAM_DETAILS.load(deps.storage, (&*pool_id.clone(), &gamer.clone())) {
-            Ok(some) => { pool_team_details = some; }
-            Err(_) => {
+            None => {
                 continue;
             }
         }
+        let entry_asset = query_pool_params(deps.storage, &game_id, &pool_id)?.entry_asset;
         let mut updated_details = Vec::new();
+        let mut pool_reward = Uint128::zero();
         for team_details in pool_team_details {
             if !team_details.claimed_reward {
                 let mut updated_team = team_details.clone();
-                user_reward += team_details.reward_amount;
+                pool_reward += team_details.reward_amount;
+                match entry_asset.clone() {
+                    Some(asset) => add_asset_amount(&mut asset_rewards, asset, team_details.reward_amount),
+                    None => user_reward += team_details.reward_amount,
+                }
+                if !team_details.reward_amount.is_zero() {
+                    events.push(GamingPoolEvent::RewardClaimed {
+                        game_id: game_id.clone(),
+                        pool_id: pool_id.clone(),
+                        gamer: gamer.clone(),
+                        team_id: team_details.team_id.clone(),
+                        amount: team_details.reward_amount,
+                    }.into_event());
+                }
                 updated_team.claimed_reward = true;
                 updated_details.push(updated_team);
             } else {
@@ -674,11 +1962,17 @@ pub fn claim_reward(
             }
         }
         if !updated_details.is_empty() {
-            POOL_TEAM_DETAILS.save(deps.storage, (&*pool_id, &gamer.clone()), &updated_details)?
+            pool_teams().save(deps.storage, (&*game_id, &*pool_id, &gamer.clone()), &updated_details)?
+        }
+        if !pool_reward.is_zero() {
+            update_pool_ledger(deps.storage, &game_id, &pool_id, |ledger| {
+                ledger.rewards_claimed += pool_reward;
+            })?;
         }
     }
 
-    if user_reward == Uint128::zero() {
+    // Pools listed with nothing left in them are only dropped from the index
+    if pools_claimed == 0 {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("No reward for this user"),
         }));
@@ -687,43 +1981,83 @@ pub fn claim_reward(
     // Do the transfer of reward to the actual gamer_addr from the contract
     let config = CONFIG.load(deps.storage)?;
     let mut messages = Vec::new();
-    let user_reward_in_ust = deps.querier.query_wasm_smart(
-        config.clone().astro_proxy_address,
-        &ProxyQueryMsgs::get_ust_equivalent_to_fury {
-            fury_count: user_reward,
-        },
-    )?;
-    let fee_details = query_platform_fees(user_reward_in_ust, config.platform_fee, config.transaction_fee)?;
-    // We only take the first coin object since we only expect UST here
-    let funds_sent;
-    if info.funds.len() != 0 {
-        funds_sent = info.funds[0].clone();
-        if (funds_sent.denom != "uusd") || (funds_sent.amount < fee_details.platform_fee.add(fee_details.transaction_fee)) {
-            return Err(ContractError::InsufficientFeesUst {});
+    for asset_reward in asset_rewards {
+        if !asset_reward.1.is_zero() {
+            messages.push(asset_transfer_msg(&asset_reward.0, recipient.clone(), asset_reward.1)?);
         }
-    } else {
-        return Err(ContractError::InsufficientFeesUst {});
     }
+    // FURY rewards pay the platform fee in UST
+    if !user_reward.is_zero() {
+        let user_reward_in_ust = oracle.ust_equivalent_to_fury(user_reward)?;
+        let fee_details = query_platform_fees(user_reward_in_ust, config.platform_fee, config.transaction_fee)?;
+        // We only take the first coin object since we only expect UST here
+        let funds_sent;
+        if info.funds.len() != 0 {
+            funds_sent = info.funds[0].clone();
+            if (funds_sent.denom != "uusd") || (funds_sent.amount < fee_details.platform_fee.add(fee_details.transaction_fee)) {
+                return Err(ContractError::InsufficientFeesUst {});
+            }
+        } else {
+            return Err(ContractError::InsufficientFeesUst {});
+        }
 
-    let r = CosmosMsg::Bank(BankMsg::Send {
-        to_address: config.platform_fees_collector_wallet.to_string(),
-        amount: info.funds,
-    });
-    messages.push(r);
-
-
-    let transfer_msg = Cw20ExecuteMsg::Transfer {
-        recipient: info.sender.into_string(),
-        amount: user_reward,
-    };
-    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: config.minting_contract_address.to_string(),
-        msg: to_binary(&transfer_msg)?,
-        funds: vec![],
-    }));
-    return Ok(Response::new()
+        let r = CosmosMsg::Bank(BankMsg::Send {
+            to_address: config.platform_fees_collector_wallet.to_string(),
+            amount: info.funds,
+        });
+        messages.push(r);
+
+
+        // Staked for the gamer, or for their payout address when one is set
+        let transfer_msg;
+        match auto_stake_club.clone() {
+            Some(club_name) => {
+                let club_staking_contract;
+                match CLUB_STAKING_CONTRACT.may_load(deps.storage)? {
+                    Some(some) => {
+                        club_staking_contract = some;
+                    }
+                    None => {
+                        return Err(ContractError::Std(StdError::GenericErr {
+                            msg: String::from("Club staking contract is not set"),
+                        }));
+                    }
+                }
+                transfer_msg = Cw20ExecuteMsg::Send {
+                    contract: club_staking_contract.to_string(),
+                    amount: user_reward,
+                    msg: to_binary(&ClubStakingReceivedMsg::StakeOnAClub(StakeOnAClubCommand {
+                        staker: recipient,
+                        club_name,
+                        auto_stake: false,
+                    }))?,
+                };
+            }
+            None => {
+                transfer_msg = Cw20ExecuteMsg::Transfer {
+                    recipient: recipient,
+                    amount: user_reward,
+                };
+            }
+        }
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.minting_contract_address.to_string(),
+            msg: to_binary(&transfer_msg)?,
+            funds: vec![],
+        }));
+    }
+    let mut rsp = Response::new()
         .add_attribute("amount", user_reward.to_string())
         .add_attribute("action", "reward")
+        .add_attribute("pools_claimed", pools_claimed.to_string());
+    match auto_stake_club {
+        Some(club_name) => {
+            rsp = rsp.add_attribute("auto_stake_club", club_name);
+        }
+        None => {}
+    }
+    return Ok(rsp
+        .add_events(events)
         .add_messages(messages)
     );
 }
@@ -739,139 +2073,382 @@ pub fn claim_refund(
     deps: DepsMut,
     info: MessageInfo,
     gamer: String,
-    env: Env,
-    testing: Option<bool>,
+    oracle: &dyn PriceOracle,
     max_spread: Option<Decimal>,
+    pool_ids: Option<Vec<String>>,
+    limit: Option<u32>,
 ) -> Result<Response, ContractError> {
-    let testing_status = testing.unwrap_or(false);
-    let mut refund_in_ust_fees = Uint128::default();
-    let gamer_addr = deps.api.addr_validate(&gamer)?;
-    //Check if withdrawer is same as invoker
-    if gamer_addr != info.sender {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
-    }
+    let recipient = claim_recipient(deps.as_ref(), &info, &gamer)?;
     let config = CONFIG.load(deps.storage)?;
-    // Get all pools
-
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(deps.storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
+    let limit = limit.unwrap_or(DEFAULT_CLAIM_LIMIT).min(MAX_CLAIM_LIMIT) as usize;
+    let mut pools_claimed = 0;
     let mut total_refund_amount = Uint128::zero();
-    for pool_id in all_pools {
-        let mut pool_details: PoolDetails = Default::default();
-        let pd = POOL_DETAILS.load(deps.storage, pool_id.clone());
-        match pd {
-            Ok(some) => { pool_details = some; }
-            Err(_) => {
-                continue;
-            }
-        }
+    let mut events: Vec<Event> = Vec::new();
+    // Refunds of pools with their own entry asset are paid back in that asset
+    let mut asset_refunds: Vec<(Denom, Uint128)> = Vec::new();
+    for (game_id, pool_id) in claimable_pools(deps.storage, &GAMER_REFUNDABLE_POOLS, &gamer, pool_ids, limit)? {
+        pools_claimed += 1;
+        GAMER_REFUNDABLE_POOLS.remove(deps.storage, (&*gamer, &*pool_id));
+        let pool_details = query_pool_details(deps.storage, game_id.clone(), pool_id.clone())?;
         if !pool_details.pool_refund_status {
             continue;
         }
-        let pool_type = POOL_TYPE_DETAILS.load(deps.storage, pool_details.pool_type)?;
+        let pool_team_details;
+        let ptd = pool_teams().may_load(deps.storage, (&*game_id, &*pool_id, &*gamer))?;
+        match ptd {
+            Some(ptd) => {
+                pool_team_details = ptd;
+            }
+            None => {
+                continue;
+            }
+        }
+        let pool_type = query_pool_params(deps.storage, &game_id, &pool_id)?;
         let refund_amount = pool_type.pool_fee;
-        let pool_team_details = POOL_TEAM_DETAILS.load(deps.storage, (pool_id.as_ref(), &gamer.clone()))?.clone();
         let mut updated_details = Vec::new();
+        let mut pool_refund = Uint128::zero();
         for team_details in pool_team_details {
             if !team_details.claimed_refund {
                 let mut updated_team = team_details.clone();
                 updated_team.refund_amount = refund_amount;
-                total_refund_amount += refund_amount;
+                pool_refund += refund_amount;
+                match pool_type.entry_asset.clone() {
+                    Some(asset) => add_asset_amount(&mut asset_refunds, asset, refund_amount),
+                    None => total_refund_amount += refund_amount,
+                }
+                events.push(GamingPoolEvent::RefundClaimed {
+                    game_id: game_id.clone(),
+                    pool_id: pool_id.clone(),
+                    gamer: gamer.clone(),
+                    team_id: team_details.team_id.clone(),
+                    amount: refund_amount,
+                }.into_event());
                 updated_team.claimed_refund = true;
                 updated_details.push(updated_team);
             } else {
-                return Err(ContractError::RefundAlreadyClaimed {});
+                updated_details.push(team_details);
             }
         }
         if !updated_details.is_empty() {
-            POOL_TEAM_DETAILS.save(deps.storage, (pool_id.as_ref(), &gamer.clone()), &updated_details)?
+            pool_teams().save(deps.storage, (game_id.as_ref(), pool_id.as_ref(), &gamer.clone()), &updated_details)?
+        }
+        if pool_refund.is_zero() {
+            continue;
         }
+        update_pool_ledger(deps.storage, &game_id, &pool_id, |ledger| {
+            ledger.refunds_claimed += pool_refund;
+        })?;
+        update_gamer_stats(deps.storage, &gamer, stake_asset(&pool_type), |_, asset_stats| {
+            asset_stats.refunded += pool_refund;
+        })?;
     }
 
-
-    if total_refund_amount == Uint128::zero() {
+    // Pools listed with nothing left in them are only dropped from the index
+    if pools_claimed == 0 {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("No refund for this user"),
         }));
     }
-    let refund_details = query_platform_fees(total_refund_amount, config.platform_fee, config.transaction_fee)?;
-    refund_in_ust_fees = refund_details.transaction_fee.add(refund_details.platform_fee);
     // Do the transfer of refund to the actual gamer_addr from the contract
     let mut messages = Vec::new();
-    let ust_asset = Asset {
-        info: AssetInfo::NativeToken {
-            denom: "uusd".to_string()
-        },
-        amount: total_refund_amount,
-    };
-    let tax = ust_asset.compute_tax(&deps.querier)?;
-    // ust_asset.amount += tax;
-    let swap_message = AstroPortExecute::Swap {
-        offer_asset: ust_asset.clone(),
-        belief_price: None,
-        max_spread: max_spread,
-        to: Option::from(info.sender.to_string()),
-    };
-
-    let mut swap_fee = Uint128::zero();
-    // Swap fee should be platform+transaction fee for the transaction
-    if !testing_status {
-        swap_fee = deps.querier.query_wasm_smart(
-            config.clone().astro_proxy_address,
-            &QueryMsgSimulation::QueryPlatformFees {
-                msg: to_binary(&swap_message)?
-            },
-        )?;
+    for asset_refund in asset_refunds {
+        if !asset_refund.1.is_zero() {
+            messages.push(asset_transfer_msg(&asset_refund.0, recipient.clone(), asset_refund.1)?);
+        }
     }
-    let final_amount = ust_asset.amount.clone().add(swap_fee).add(tax);
-    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: config.astro_proxy_address.to_string(),
-        msg: to_binary(&swap_message)?,
-        funds: vec![Coin {
+    // Pool fees collected in UST are refunded as FURY
+    let mut final_amount = Uint128::zero();
+    if !total_refund_amount.is_zero() {
+        let refund_details = query_platform_fees(total_refund_amount, config.platform_fee, config.transaction_fee)?;
+        let refund_in_ust_fees = refund_details.transaction_fee.add(refund_details.platform_fee);
+        let ust_asset = Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uusd".to_string()
+            },
+            amount: total_refund_amount,
+        };
+        let tax = oracle.tax(&ust_asset)?;
+        // ust_asset.amount += tax;
+        let swap_message = AstroPortExecute::Swap {
+            offer_asset: ust_asset.clone(),
+            belief_price: None,
+            max_spread: max_spread,
+            to: Option::from(recipient.clone()),
+        };
+
+        // Swap fee should be platform+transaction fee for the transaction
+        let swap_fee = oracle.swap_fee(&swap_message)?;
+        final_amount = ust_asset.amount.clone().add(swap_fee).add(tax);
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.astro_proxy_address.to_string(),
+            msg: to_binary(&swap_message)?,
+            funds: vec![Coin {
+                denom: "uusd".to_string(),
+                amount: final_amount,
+            }],
+        }));
+        let refund = Coin {
             denom: "uusd".to_string(),
-            amount: final_amount,
-        }],
-    }));
-    let refund = Coin {
-        denom: "uusd".to_string(),
-        amount: refund_in_ust_fees,
-    };
-    let mut refund_: Vec<Coin> = vec![];
-    refund_.push(refund);
-    messages.push(CosmosMsg::Bank(BankMsg::Send {
-        to_address: String::from(info.sender),
-        amount: refund_,
-    }));
+            amount: refund_in_ust_fees,
+        };
+        let mut refund_: Vec<Coin> = vec![];
+        refund_.push(refund);
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient,
+            amount: refund_,
+        }));
+    }
+    return Ok(Response::new()
+        .add_attribute("amount", final_amount.to_string())
+        .add_attribute("action", "refund")
+        .add_attribute("pools_claimed", pools_claimed.to_string())
+        .add_events(events)
+        .add_messages(messages)
+    );
+}
+
+// A gamer cannot hold the same team id twice in a pool, whatever the number
+// of teams the pool type lets them enter
+pub fn update_team(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    pool_id: String,
+    old_team_id: String,
+    new_team_id: String,
+) -> Result<Response, ContractError> {
+    let gamer = info.sender.to_string();
+    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    let game;
+    match gd {
+        Some(gd) => {
+            game = gd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Game status cannot be retrieved"),
+            }));
+        }
+    }
+    if get_game_status(&game, &env.block) != GAME_POOL_OPEN {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Teams can only be updated while the game is open"),
+        }));
+    }
+
+    let mut all_teams;
+    let ptd = pool_teams().may_load(deps.storage, (&*game_id, &*pool_id, &*gamer))?;
+    match ptd {
+        Some(ptd) => {
+            all_teams = ptd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("No team found for this user in the pool"),
+            }));
+        }
+    }
+    // A team id is unique among the gamer's teams in all pools of the pool type
+    let pool_type = query_pool_details(deps.storage, game_id.clone(), pool_id.clone())?.pool_type;
+    let gamer_pools: Vec<Vec<PoolTeamDetails>> = pool_teams()
+        .idx
+        .gamer
+        .prefix((gamer.as_bytes().to_vec(), game_id.as_bytes().to_vec()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, teams)| teams))
+        .collect::<StdResult<Vec<Vec<PoolTeamDetails>>>>()?;
+    for teams in gamer_pools {
+        for team in teams {
+            if team.pool_type == pool_type && team.team_id == new_team_id {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: String::from("User already has this team in a pool of this pool type"),
+                }));
+            }
+        }
+    }
+    let mut updated = false;
+    for team in all_teams.iter_mut() {
+        if team.team_id == old_team_id {
+            team.team_changes.push(TeamChange {
+                previous_team_id: old_team_id.clone(),
+                changed_at: env.block.time,
+            });
+            team.team_id = new_team_id.clone();
+            updated = true;
+            break;
+        }
+    }
+    if !updated {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("No team found for this user in the pool"),
+        }));
+    }
+    pool_teams().save(deps.storage, (&*game_id, &*pool_id, &*gamer), &all_teams)?;
+
+    let event = GamingPoolEvent::TeamUpdated {
+        game_id,
+        pool_id,
+        gamer,
+        team_id: new_team_id,
+        previous_team_id: old_team_id,
+    }.into_event();
+    return Ok(Response::new()
+        .add_attribute("action", "update_team")
+        .add_event(event));
+}
+
+// The pool fee minus the withdrawal fee goes back the way a refund does, the
+// withdrawal fee is sent to the platform fee collector
+pub fn withdraw_team(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    pool_id: String,
+    team_id: String,
+    oracle: &dyn PriceOracle,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let gamer = info.sender.to_string();
+    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    let game;
+    match gd {
+        Some(gd) => {
+            game = gd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Game status cannot be retrieved"),
+            }));
+        }
+    }
+    if get_game_status(&game, &env.block) != GAME_POOL_OPEN {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Teams can only be withdrawn while the game is open"),
+        }));
+    }
+
+    let all_teams;
+    let ptd = pool_teams().may_load(deps.storage, (&*game_id, &*pool_id, &*gamer))?;
+    match ptd {
+        Some(ptd) => {
+            all_teams = ptd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("No team found for this user in the pool"),
+            }));
+        }
+    }
+    let mut remaining_teams = Vec::new();
+    let mut withdrawn = false;
+    for team in all_teams {
+        if !withdrawn && team.team_id == team_id {
+            withdrawn = true;
+        } else {
+            remaining_teams.push(team);
+        }
+    }
+    if !withdrawn {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("No team found for this user in the pool"),
+        }));
+    }
+    if remaining_teams.is_empty() {
+        pool_teams().remove(deps.storage, (&*game_id, &*pool_id, &*gamer))?;
+    } else {
+        pool_teams().save(deps.storage, (&*game_id, &*pool_id, &*gamer), &remaining_teams)?;
+    }
+
+    let mut pool_details = query_pool_details(deps.storage, game_id.clone(), pool_id.clone())?;
+    pool_details.current_teams_count -= 1;
+    pools().save(deps.storage, (&*game_id, &*pool_id), &pool_details)?;
+    let pool_type = query_pool_params(deps.storage, &game_id, &pool_id)?;
+    update_pool_ledger(deps.storage, &game_id, &pool_id, |ledger| {
+        ledger.collected = ledger.collected.checked_sub(pool_type.pool_fee).unwrap_or_default();
+    })?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let recipient = claim_recipient(deps.as_ref(), &info, &gamer)?;
+    let refund_amount = pool_type.pool_fee.checked_sub(pool_type.withdrawal_fee).map_err(StdError::overflow)?;
+    let mut messages = Vec::new();
+    match pool_type.entry_asset.clone() {
+        Some(asset) => {
+            if !refund_amount.is_zero() {
+                messages.push(asset_transfer_msg(&asset, recipient.clone(), refund_amount)?);
+            }
+            if !pool_type.withdrawal_fee.is_zero() {
+                messages.push(asset_transfer_msg(
+                    &asset,
+                    config.platform_fees_collector_wallet.to_string(),
+                    pool_type.withdrawal_fee,
+                )?);
+            }
+        }
+        None => {
+            // Pool fees collected in UST are refunded as FURY
+            if !refund_amount.is_zero() {
+                let ust_asset = Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "uusd".to_string()
+                    },
+                    amount: refund_amount,
+                };
+                let tax = oracle.tax(&ust_asset)?;
+                let swap_message = AstroPortExecute::Swap {
+                    offer_asset: ust_asset.clone(),
+                    belief_price: None,
+                    max_spread: max_spread,
+                    to: Option::from(recipient.clone()),
+                };
+                let swap_fee = oracle.swap_fee(&swap_message)?;
+                let final_amount = ust_asset.amount.add(swap_fee).add(tax);
+                messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: config.astro_proxy_address.to_string(),
+                    msg: to_binary(&swap_message)?,
+                    funds: vec![Coin {
+                        denom: "uusd".to_string(),
+                        amount: final_amount,
+                    }],
+                }));
+            }
+            if !pool_type.withdrawal_fee.is_zero() {
+                messages.push(asset_transfer_msg(
+                    &Denom::Native("uusd".to_string()),
+                    config.platform_fees_collector_wallet.to_string(),
+                    pool_type.withdrawal_fee,
+                )?);
+            }
+        }
+    }
+    update_gamer_stats(deps.storage, &gamer, stake_asset(&pool_type), |_, asset_stats| {
+        asset_stats.refunded += refund_amount;
+    })?;
+    let event = GamingPoolEvent::TeamWithdrawn {
+        game_id,
+        pool_id,
+        gamer,
+        team_id,
+        amount: refund_amount,
+    }.into_event();
     return Ok(Response::new()
-        .add_attribute("amount", final_amount.to_string())
-        .add_attribute("action", "refund")
-        .add_messages(messages)
-    );
+        .add_attribute("action", "withdraw_team")
+        .add_attribute("withdrawal_fee", pool_type.withdrawal_fee.to_string())
+        .add_event(event)
+        .add_messages(messages));
 }
 
 pub fn game_pool_reward_distribute(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    game_id: String,
     pool_id: String,
-    game_winners: Vec<GameResult>,
+    game_winners: Vec<TeamRanking>,
     is_final_batch: bool,
-    testing: bool,
-    ust_for_rake: Uint128,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
-    }
-    let platform_fee_in_percentage = config.platform_fee;
-    let platform_fee;
-    let game_id = config.game_id.clone();
+    assert_game_operator(deps.as_ref(), &info.sender)?;
 
     let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
     let game;
@@ -916,35 +2493,43 @@ pub fn game_pool_reward_distribute(
         &GameDetails {
             game_id: game_id.clone(),
             game_status: game_status,
+            entry_close_time: game.entry_close_time,
         },
     )?;
 
-    let pool_details = query_pool_details(deps.storage, pool_id.clone())?;
+    let pool_details = query_pool_details(deps.storage, game_id.clone(), pool_id.clone())?;
     if pool_details.rewards_distributed == REWARDS_DISTRIBUTED {
         return Err(ContractError::Std(StdError::GenericErr {
             msg: String::from("Rewards are already distributed for this pool"),
         }));
     }
-    let pool_count = pool_details.current_teams_count;
-    let pool_type = pool_details.pool_type;
+    // Teams of a pool flagged for refund get their pool fee back instead
+    if pool_details.pool_refund_status {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool is flagged for refund"),
+        }));
+    }
+    let pool_type = pool_details.pool_type.clone();
 
 
-    POOL_DETAILS.save(
-        deps.storage,
-        pool_id.clone(),
-        &PoolDetails {
-            game_id: game_id.clone(),
-            pool_id: pool_id.clone(),
-            pool_type: pool_type.clone(),
-            current_teams_count: pool_details.current_teams_count,
-            rewards_distributed: reward_status,
-            pool_refund_status: false,
-            pool_reward_status: true,
-        },
-    )?;
+    track_pool_obligations(deps.storage, &game_id, &pool_id, |storage| {
+        pools().save(
+            storage,
+            (&*game_id, &*pool_id),
+            &PoolDetails {
+                game_id: game_id.clone(),
+                pool_id: pool_id.clone(),
+                pool_type: pool_type.clone(),
+                current_teams_count: pool_details.current_teams_count,
+                rewards_distributed: reward_status,
+                pool_refund_status: false,
+                pool_reward_status: true,
+            },
+        )
+    })?;
 
     let pool_type_details;
-    let ptd = POOL_TYPE_DETAILS.may_load(deps.storage, pool_type.clone())?;
+    let ptd = may_load_pool_params(deps.storage, &game_id, &pool_id)?;
     match ptd {
         Some(ptd) => {
             pool_type_details = ptd;
@@ -955,23 +2540,26 @@ pub fn game_pool_reward_distribute(
             }));
         }
     }
-    platform_fee = query_platform_fees(pool_type_details.pool_fee, platform_fee_in_percentage, config.transaction_fee.clone())?.platform_fee;
-
-    let pool_fee: Uint128 = pool_type_details.pool_fee;
-
-
-    let mut winner_rewards = Uint128::zero();
-    let winners = game_winners.clone();
-    for winner in winners {
-        winner_rewards += winner.reward_amount;
-    }
+    let (prize_pool, rake_amount, payout_asset, stats_exchange_rate) =
+        pool_payout(deps.storage, &game_id, &pool_id, &pool_type_details)?;
+
+    let mut reward_details = POOL_REWARD_DETAILS
+        .may_load(deps.storage, (&*game_id, &*pool_id))?
+        .unwrap_or_default();
+    let winner_rewards = compute_team_rewards(
+        &pool_type_details.payout_tiers,
+        &game_winners,
+        prize_pool,
+        &reward_details.ranks_assigned,
+    )?;
 
     let mut wallet_transfer_details: Vec<WalletTransferDetails> = Vec::new();
 
     let mut reward_given_so_far = Uint128::zero();
-    let mut all_teams: Vec<PoolTeamDetails> = Vec::new();
-    for winner in game_winners.clone().into_iter() {
-        let ptd = POOL_TEAM_DETAILS.may_load(deps.storage, (&pool_id.clone(), winner.gamer_address.as_ref()))?;
+    let mut events: Vec<Event> = Vec::new();
+    for (winner, reward) in game_winners.iter().zip(winner_rewards.into_iter()) {
+        let mut all_teams: Vec<PoolTeamDetails>;
+        let ptd = pool_teams().may_load(deps.storage, (&game_id.clone(), &pool_id.clone(), winner.gamer_address.as_ref()))?;
         match ptd {
             Some(ptd) => {
                 all_teams = ptd;
@@ -980,68 +2568,125 @@ pub fn game_pool_reward_distribute(
                 continue;
             }
         }
-        let mut updated_teams: Vec<PoolTeamDetails> = Vec::new();
-        for team in &all_teams {
-            // No transfer to be done to the winners. Just update their reward amounts.
-            // They have to come and collect their rewards
-            let mut updated_team = team.clone();
-            let winners = game_winners.clone();
-            for winner in winners {
-                if team.gamer_address == winner.gamer_address
-                    && team.team_id == winner.team_id
-                    && team.game_id == winner.game_id
-                {
-                    updated_team.reward_amount = winner.reward_amount;
-                    updated_team.team_rank = winner.team_rank;
-                    updated_team.team_points = winner.team_points;
-                    reward_given_so_far += winner.reward_amount;
-                    println!(
-                        "reward for {:?} is {:?}",
-                        team.team_id, updated_team.reward_amount
-                    );
+        // No transfer to be done to the winners. Just update their reward amounts.
+        // They have to come and collect their rewards
+        for team in all_teams.iter_mut() {
+            if team.team_id == winner.team_id {
+                if team.team_rank != INITIAL_TEAM_RANK {
+                    POOL_LEADERBOARD.remove(deps.storage, (&*game_id, &*pool_id, &leaderboard_key(team)));
                 }
+                team.reward_amount = reward;
+                team.team_rank = winner.team_rank;
+                team.team_points = winner.team_points;
+                POOL_LEADERBOARD.save(
+                    deps.storage,
+                    (&*game_id, &*pool_id, &leaderboard_key(team)),
+                    &(team.gamer_address.clone(), team.team_id.clone()),
+                )?;
+                reward_given_so_far += reward;
+                if !reward.is_zero() {
+                    GAMER_CLAIMABLE_POOLS.save(deps.storage, (&*winner.gamer_address, &*pool_id), &game_id)?;
+                }
+                events.push(GamingPoolEvent::TeamResult {
+                    game_id: game_id.clone(),
+                    pool_id: pool_id.clone(),
+                    gamer: winner.gamer_address.clone(),
+                    team_id: winner.team_id.clone(),
+                    team_rank: winner.team_rank,
+                    team_points: winner.team_points,
+                    amount: reward,
+                }.into_event());
+                break;
             }
-            updated_teams.push(updated_team);
-        }
-        POOL_TEAM_DETAILS.save(deps.storage, (&pool_id.clone(), winner.gamer_address.as_ref()), &updated_teams)?;
-    }
-    let current_reward = CURRENT_REWARD_FOR_POOL.load(deps.storage, pool_id.clone());
-    let reward_total;
-    match current_reward {
-        Ok(some) => {
-            let total_current = some.add(reward_given_so_far.clone());
-            CURRENT_REWARD_FOR_POOL.save(deps.storage, pool_id.clone(), &total_current)?;
-            reward_total = total_current;
         }
-        Err(_) => {
-            reward_total = reward_given_so_far;
-            CURRENT_REWARD_FOR_POOL.save(deps.storage, pool_id.clone(), &reward_given_so_far)?;
+        pool_teams().save(deps.storage, (&game_id.clone(), &pool_id.clone(), winner.gamer_address.as_ref()), &all_teams)?;
+        let won;
+        match stats_exchange_rate {
+            Some(rate) if !rate.is_zero() => won = reward.multiply_ratio(10000u128, rate),
+            Some(_) => won = Uint128::zero(),
+            None => won = reward,
         }
+        update_gamer_stats(deps.storage, &winner.gamer_address, stake_asset(&pool_type_details), |stats, asset_stats| {
+            if winner.team_rank == 1 {
+                stats.wins += 1;
+            }
+            asset_stats.won += won;
+        })?;
+    }
+
+    reward_details.reward_assigned += reward_given_so_far;
+    if reward_details.reward_assigned > prize_pool {
+        return Err(ContractError::ValueMismatch {
+            reward_in_fury: reward_details.reward_assigned,
+            reward_in_total: prize_pool,
+        });
     }
+    reward_details.ranks_assigned.extend(occupied_ranks(&game_winners)?);
+    POOL_REWARD_DETAILS.save(deps.storage, (&*game_id, &*pool_id), &reward_details)?;
+    update_pool_ledger(deps.storage, &game_id, &pool_id, |ledger| {
+        ledger.rewards_assigned += reward_given_so_far;
+    })?;
+
     // let mut swap_info = query_swap_data_for_pool(deps.storage, "1".to_string().clone())?;
     let rsp;
-    // Transfer rake_amount to all the rake wallets. Can also be only one rake wallet
+    // Transfer rake_amount to all the rake wallets. Can also be only one rake wallet.
+    // The creator of a player pool takes its share out of every wallet's amount
     if is_final_batch {
+        let user_pool = USER_POOLS.may_load(deps.storage, (&*game_id, &*pool_id))?;
+        let mut creator_amount = Uint128::zero();
+        let mut rake_paid = Uint128::zero();
         for wallet in pool_type_details.rake_list {
             let wallet_address = wallet.wallet_address;
-            let rake_amount = ust_for_rake;
-            let proportionate_amount = rake_amount
+            let mut proportionate_amount = rake_amount
                 .checked_mul(Uint128::from(wallet.percentage))
                 .unwrap_or_default()
                 .checked_div(Uint128::from(100u128))
                 .unwrap_or_default();
+            match user_pool.clone() {
+                Some(user_pool) => {
+                    let creator_share = proportionate_amount.multiply_ratio(user_pool.creator_rake_percentage, 100u128);
+                    creator_amount += creator_share;
+                    proportionate_amount -= creator_share;
+                }
+                None => {}
+            }
             // Transfer proportionate_amount to the corresponding rake wallet
             let transfer_detail = WalletTransferDetails {
                 wallet_address: wallet_address.clone(),
                 amount: proportionate_amount,
             };
+            events.push(GamingPoolEvent::RakePaid {
+                game_id: game_id.clone(),
+                pool_id: pool_id.clone(),
+                wallet_address: wallet_address.clone(),
+                amount: proportionate_amount,
+            }.into_event());
+            rake_paid += proportionate_amount;
             wallet_transfer_details.push(transfer_detail);
         }
+        match user_pool {
+            Some(user_pool) if !creator_amount.is_zero() => {
+                events.push(GamingPoolEvent::RakePaid {
+                    game_id: game_id.clone(),
+                    pool_id: pool_id.clone(),
+                    wallet_address: user_pool.creator.to_string(),
+                    amount: creator_amount,
+                }.into_event());
+                rake_paid += creator_amount;
+                wallet_transfer_details.push(WalletTransferDetails {
+                    wallet_address: user_pool.creator.to_string(),
+                    amount: creator_amount,
+                });
+            }
+            _ => {}
+        }
+        update_pool_ledger(deps.storage, &game_id, &pool_id, |ledger| {
+            ledger.rake_paid += rake_paid;
+        })?;
         rsp = _transfer_to_multiple_wallets(
             wallet_transfer_details,
+            payout_asset,
             "rake_and_platform_fee".to_string(),
-            deps,
-            testing,
         )?;
         // rsp = Response::new();
     } else {
@@ -1051,30 +2696,296 @@ pub fn game_pool_reward_distribute(
         .add_attribute("game_status", reward_status_string.to_string())
         .add_attribute("game_id", game_id.clone())
         .add_attribute("pool_status", pool_status_string.to_string())
-        .add_attribute("pool_id", pool_id.clone()));
+        .add_attribute("pool_id", pool_id.clone())
+        .add_events(events));
+}
+
+// What the pool pays out: the prize pool, the rake, the asset the rake is paid
+// in and, for pools paid in FURY, the swap rate their rewards are counted at
+// in the gamer stats
+fn pool_payout(
+    storage: &dyn Storage,
+    game_id: &str,
+    pool_id: &str,
+    pool_type_details: &PoolTypeDetails,
+) -> Result<(Uint128, Uint128, Denom, Option<Uint128>), ContractError> {
+    // Rewards can only come out of what the pool actually collected
+    let total_collection_in_pool = query_pool_collection(storage, game_id.to_string(), pool_id.to_string())?;
+    let prize_pool;
+    let rake_amount;
+    let payout_asset;
+    let stats_exchange_rate;
+    match pool_type_details.entry_asset.clone() {
+        None => {
+            let swap_info;
+            match SWAP_BALANCE_INFO.may_load(storage, pool_id.to_string())? {
+                Some(some) => {
+                    swap_info = some;
+                }
+                None => {
+                    return Err(ContractError::SwapInfoNotFound {});
+                }
+            }
+            if swap_info.ust_amount_swapped > total_collection_in_pool {
+                return Err(ContractError::InvalidSwap {
+                    total_collection_in_pool,
+                    amount_to_swap: swap_info.ust_amount_swapped,
+                });
+            }
+            // The prize pool is the FURY bought with the pool collection minus the rake
+            prize_pool = swap_info.balance_post_swap
+                .checked_sub(swap_info.balance_pre_swap)
+                .unwrap_or_default();
+            rake_amount = swap_info.ust_for_rake;
+            payout_asset = Denom::Native("uusd".to_string());
+            stats_exchange_rate = Some(swap_info.exchange_rate);
+        }
+        Some(entry_asset) => {
+            // Nothing is swapped, the rake is taken straight out of the collection
+            let mut total_rake = Uint128::zero();
+            for wallet in pool_type_details.rake_list.iter() {
+                total_rake += total_collection_in_pool.multiply_ratio(wallet.percentage, 100u128);
+            }
+            prize_pool = total_collection_in_pool.checked_sub(total_rake).map_err(StdError::overflow)?;
+            rake_amount = total_collection_in_pool;
+            payout_asset = entry_asset;
+            stats_exchange_rate = None;
+        }
+    }
+
+    return Ok((prize_pool, rake_amount, payout_asset, stats_exchange_rate));
+}
+
+pub fn set_result_dispute_params(
+    deps: DepsMut,
+    info: MessageInfo,
+    challenge_period: Duration,
+    oracles: Vec<String>,
+    veto_threshold: u32,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info.sender)?;
+    if veto_threshold == 0 {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Veto threshold must be at least 1"),
+        }));
+    }
+    for oracle in oracles.iter() {
+        deps.api.addr_validate(oracle)?;
+    }
+    RESULT_DISPUTE_CONFIG.save(
+        deps.storage,
+        &ResultDisputeConfig {
+            challenge_period: challenge_period,
+            oracles: oracles,
+            veto_threshold: veto_threshold,
+        },
+    )?;
+    return Ok(Response::new()
+        .add_attribute("challenge_period", challenge_period.to_string())
+        .add_attribute("veto_threshold", veto_threshold.to_string()));
+}
+
+// Results are not applied right away. They stay pending until the
+// challenge period is over so that the oracles get a chance to veto them
+pub fn propose_game_results(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    pool_id: String,
+    game_winners: Vec<TeamRanking>,
+    is_final_batch: bool,
+) -> Result<Response, ContractError> {
+    assert_game_operator(deps.as_ref(), &info.sender)?;
+
+    let gd = GAME_DETAILS.may_load(deps.storage, game_id.clone())?;
+    let game;
+    match gd {
+        Some(gd) => {
+            game = gd;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Game status cannot be retrieved"),
+            }));
+        }
+    }
+    if game.game_status == GAME_CANCELLED {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Results cant be proposed as game is cancelled"),
+        }));
+    }
+    if game.game_status == GAME_POOL_OPEN {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Results cant be proposed as game not yet started"),
+        }));
+    }
+
+    let pool_details = query_pool_details(deps.storage, game_id.clone(), pool_id.clone())?;
+    if pool_details.rewards_distributed == REWARDS_DISTRIBUTED {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rewards are already distributed for this pool"),
+        }));
+    }
+    // Teams of a pool flagged for refund get their pool fee back instead
+    if pool_details.pool_refund_status {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool is flagged for refund"),
+        }));
+    }
+    if PENDING_POOL_RESULTS.may_load(deps.storage, (&*game_id, &*pool_id))?.is_some() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Results are already pending for this pool"),
+        }));
+    }
+    // Results that could not be distributed are refused now, rather than
+    // failing every finalize attempt later
+    let pool_type_details = query_pool_params(deps.storage, &game_id, &pool_id)?;
+    let (prize_pool, _, _, _) = pool_payout(deps.storage, &game_id, &pool_id, &pool_type_details)?;
+    let reward_details = POOL_REWARD_DETAILS
+        .may_load(deps.storage, (&*game_id, &*pool_id))?
+        .unwrap_or_default();
+    let winner_rewards = compute_team_rewards(
+        &pool_type_details.payout_tiers,
+        &game_winners,
+        prize_pool,
+        &reward_details.ranks_assigned,
+    )?;
+    let mut reward_assigned = reward_details.reward_assigned;
+    for reward in winner_rewards {
+        reward_assigned += reward;
+    }
+    if reward_assigned > prize_pool {
+        return Err(ContractError::ValueMismatch {
+            reward_in_fury: reward_assigned,
+            reward_in_total: prize_pool,
+        });
+    }
+
+    let dispute_config = query_result_dispute_config(deps.storage)?;
+    let challenge_end = dispute_config.challenge_period.after(&env.block);
+    PENDING_POOL_RESULTS.save(
+        deps.storage,
+        (&*game_id, &*pool_id),
+        &PendingPoolResults {
+            game_winners: game_winners,
+            is_final_batch: is_final_batch,
+            challenge_end: challenge_end,
+            votes: Vec::new(),
+        },
+    )?;
+    return Ok(Response::new()
+        .add_attribute("results_status", "RESULTS_PENDING".to_string())
+        .add_attribute("game_id", game_id.clone())
+        .add_attribute("pool_id", pool_id.clone())
+        .add_attribute("challenge_end", challenge_end.to_string()));
+}
+
+// Pending results can be taken back by the operator, so that wrong results
+// can be proposed again without waiting for a veto
+pub fn withdraw_game_results(
+    deps: DepsMut,
+    info: MessageInfo,
+    game_id: String,
+    pool_id: String,
+) -> Result<Response, ContractError> {
+    assert_game_operator(deps.as_ref(), &info.sender)?;
+    query_pending_game_results(deps.storage, game_id.clone(), pool_id.clone())?;
+    PENDING_POOL_RESULTS.remove(deps.storage, (&*game_id, &*pool_id));
+    return Ok(Response::new()
+        .add_attribute("results_status", "RESULTS_WITHDRAWN".to_string())
+        .add_attribute("game_id", game_id)
+        .add_attribute("pool_id", pool_id));
+}
+
+pub fn vote_game_results(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    pool_id: String,
+    vote: Vote,
+) -> Result<Response, ContractError> {
+    let dispute_config = query_result_dispute_config(deps.storage)?;
+    if !dispute_config.oracles.contains(&info.sender.to_string()) {
+        return Err(ContractError::Unauthorized {
+            invoker: info.sender.to_string(),
+        });
+    }
+    let mut pending_results = query_pending_game_results(deps.storage, game_id.clone(), pool_id.clone())?;
+    if pending_results.challenge_end.is_expired(&env.block) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Challenge period is over"),
+        }));
+    }
+    for result_vote in pending_results.votes.iter() {
+        if result_vote.oracle == info.sender.to_string() {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Oracle has already voted on these results"),
+            }));
+        }
+    }
+    pending_results.votes.push(ResultVote {
+        oracle: info.sender.to_string(),
+        vote: vote,
+    });
+
+    let mut vetoes = 0u32;
+    for result_vote in pending_results.votes.iter() {
+        if result_vote.vote == Vote::No || result_vote.vote == Vote::Veto {
+            vetoes += 1;
+        }
+    }
+    let results_status;
+    if vetoes >= dispute_config.veto_threshold {
+        // Vetoed results are dropped, the admin has to propose them again
+        PENDING_POOL_RESULTS.remove(deps.storage, (&*game_id, &*pool_id));
+        results_status = "RESULTS_VETOED";
+    } else {
+        PENDING_POOL_RESULTS.save(deps.storage, (&*game_id, &*pool_id), &pending_results)?;
+        results_status = "RESULTS_PENDING";
+    }
+    return Ok(Response::new()
+        .add_attribute("results_status", results_status.to_string())
+        .add_attribute("game_id", game_id.clone())
+        .add_attribute("pool_id", pool_id.clone())
+        .add_attribute("oracle", info.sender.to_string()));
+}
+
+pub fn finalize_game_results(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    game_id: String,
+    pool_id: String,
+) -> Result<Response, ContractError> {
+    let pending_results = query_pending_game_results(deps.storage, game_id.clone(), pool_id.clone())?;
+    if !pending_results.challenge_end.is_expired(&env.block) {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Challenge period has not ended yet"),
+        }));
+    }
+    PENDING_POOL_RESULTS.remove(deps.storage, (&*game_id, &*pool_id));
+    let rsp = game_pool_reward_distribute(
+        deps,
+        env,
+        info,
+        game_id,
+        pool_id,
+        pending_results.game_winners,
+        pending_results.is_final_batch,
+    )?;
+    return Ok(rsp.add_attribute("results_status", "RESULTS_FINALIZED".to_string()));
 }
 
 pub fn _transfer_to_multiple_wallets(
     wallet_details: Vec<WalletTransferDetails>,
+    asset: Denom,
     action: String,
-    deps: DepsMut,
-    testing: bool,
 ) -> Result<Response, ContractError> {
-    let config = CONFIG.load(deps.storage)?;
     let mut rsp = Response::new();
-    if testing {
-        return Ok(rsp);
-    }
     for wallet in wallet_details {
-        let mut funds_to_send = vec![Coin {
-            denom: "uusd".to_string(),
-            amount: wallet.amount,
-        }];
-        let transfer_msg = CosmosMsg::Bank(BankMsg::Send {
-            to_address: wallet.wallet_address,
-            amount: funds_to_send,
-        });
-
+        let transfer_msg = asset_transfer_msg(&asset, wallet.wallet_address, wallet.amount)?;
         rsp = rsp.add_message(transfer_msg);
     }
     let data_msg = format!("Amount transferred").into_bytes();
@@ -1086,17 +2997,53 @@ pub fn swap(
     env: Env,
     info: MessageInfo,
     amount: Uint128,
+    game_id: String,
     pool_id: String,
+    oracle: &dyn PriceOracle,
     max_spread: Option<Decimal>,
+    min_fury_received: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if info.sender != config.admin_address {
-        return Err(ContractError::Unauthorized {
-            invoker: info.sender.to_string(),
-        });
+    assert_game_operator(deps.as_ref(), &info.sender)?;
+    if amount.is_zero() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Nothing to swap"),
+        }));
+    }
+    // Refunds are paid out of the UST collected, so it is kept as it is
+    let game = query_game_details(deps.storage, &env.block, game_id.clone())?;
+    if game.game_status == GAME_CANCELLED {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool cant be swapped as game is cancelled"),
+        }));
+    }
+    let pool_details = query_pool_details(deps.storage, game_id.clone(), pool_id.clone())?;
+    if pool_details.pool_refund_status {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool is flagged for refund"),
+        }));
+    }
+    let pool_type_details = query_pool_params(deps.storage, &game_id, &pool_id)?;
+    if pool_type_details.entry_asset.is_some() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool entry asset is not swapped"),
+        }));
+    }
+    // The swap info is only saved once a swap went through, failed swaps
+    // can be sent again
+    if SWAP_BALANCE_INFO.may_load(deps.storage, pool_id.clone())?.is_some() {
+        return Err(ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool collection is already swapped"),
+        }));
+    }
+    let mut history = SWAP_HISTORY.may_load(deps.storage, (&*game_id, &*pool_id))?.unwrap_or_default();
+    for attempt in history.iter() {
+        if attempt.status == SwapStatus::Pending {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("A swap is already pending for this pool"),
+            }));
+        }
     }
-    let pool_details = query_pool_details(deps.storage, pool_id.clone())?;
-    let pool_type_details = POOL_TYPE_DETAILS.load(deps.storage, pool_details.pool_type.clone())?;
     // This is the total funds we have in the pool as UST
     let total_collection_in_pool = pool_type_details.pool_fee.checked_mul(Uint128::from(pool_details.current_teams_count)).unwrap_or_default();
     //  We need the amount to be less else there is no funds left for rake
@@ -1112,32 +3059,37 @@ pub fn swap(
             address: env.contract.address.clone().to_string()
         },
     )?;
-    let mut swap_info;
-    match SWAP_BALANCE_INFO.load(deps.storage, pool_id.clone()) {
-        Ok(mut swap) => {
-            swap_info = swap;
-            swap_info.ust_amount_swapped = amount;
-            swap_info.ust_for_rake = funds_for_rake;
-        }
-        Err(_) => {
-            swap_info = SwapBalanceDetails {
-                balance_pre_swap: Default::default(),
-                balance_post_swap: Default::default(),
-                exchange_rate: Default::default(),
-                ust_amount_swapped: amount.clone(),
-                ust_for_rake: funds_for_rake,
-            }
-        }
-    }
-    swap_info.balance_pre_swap = current_fury_balance.balance;
-    SWAP_BALANCE_INFO.save(deps.storage, pool_id.clone(), &swap_info)?;
+
+    let reply_id = LAST_SWAP_REPLY_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    LAST_SWAP_REPLY_ID.save(deps.storage, &reply_id)?;
+    history.push(SwapAttempt {
+        reply_id,
+        status: SwapStatus::Pending,
+        ust_amount: amount,
+        ust_for_rake: funds_for_rake,
+        min_fury_received: min_fury_received.unwrap_or_default(),
+        balance_pre_swap: current_fury_balance.balance,
+        fury_received: Uint128::zero(),
+        sent_at: env.block.time,
+        error: None,
+    });
+    SWAP_HISTORY.save(deps.storage, (&*game_id, &*pool_id), &history)?;
+    SWAP_REPLIES.save(
+        deps.storage,
+        U64Key::from(reply_id),
+        &SwapReplyTarget {
+            game_id: game_id.clone(),
+            pool_id: pool_id.clone(),
+        },
+    )?;
+
     let ust_asset = Asset {
         info: AssetInfo::NativeToken {
             denom: "uusd".to_string()
         },
         amount,
     };
-    let tax = ust_asset.compute_tax(&deps.querier)?;
+    let tax = oracle.tax(&ust_asset)?;
     let swap_message = AstroPortExecute::Swap {
         offer_asset: ust_asset.clone(),
         belief_price: None,
@@ -1146,15 +3098,10 @@ pub fn swap(
     };
 
     // Swap fee should be platform+transaction fee for the transaction
-    let swap_fee: Uint128 = deps.querier.query_wasm_smart(
-        config.clone().astro_proxy_address,
-        &QueryMsgSimulation::QueryPlatformFees {
-            msg: to_binary(&swap_message)?
-        },
-    )?;
+    let swap_fee = oracle.swap_fee(&swap_message)?;
     let final_amount = ust_asset.amount.clone().add(swap_fee).add(tax);
 
-    let submsg = SubMsg::reply_on_success(
+    let submsg = SubMsg::reply_always(
         CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: config.astro_proxy_address.to_string(),
             msg: to_binary(&swap_message)?,
@@ -1163,19 +3110,134 @@ pub fn swap(
                 amount: final_amount,
             }],
         }),
-        pool_id.parse::<u64>().unwrap(),
+        reply_id,
     );
-    return Ok(Response::new().add_submessage(submsg).add_attribute("fury_balance_pre_swap", current_fury_balance.balance.to_string()));
+    return Ok(Response::new()
+        .add_submessage(submsg)
+        .add_attribute("fury_balance_pre_swap", current_fury_balance.balance.to_string())
+        .add_attribute("reply_id", reply_id.to_string()));
+}
+
+// A swap bringing in less than the minimum FURY is reverted along with the
+// transaction that sent it, a swap the proxy rejected is kept as failed
+pub fn swap_reply(
+    deps: DepsMut,
+    env: Env,
+    reply_id: u64,
+    result: ContractResult<SubMsgExecutionResponse>,
+) -> Result<Response, ContractError> {
+    let target;
+    match SWAP_REPLIES.may_load(deps.storage, U64Key::from(reply_id))? {
+        Some(some) => {
+            target = some;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("No swap found for this reply"),
+            }));
+        }
+    }
+    SWAP_REPLIES.remove(deps.storage, U64Key::from(reply_id));
+    let game_id = target.game_id;
+    let pool_id = target.pool_id;
+    let mut history = SWAP_HISTORY.load(deps.storage, (&*game_id, &*pool_id))?;
+    let attempt;
+    match history.iter_mut().find(|a| a.reply_id == reply_id) {
+        Some(some) => {
+            attempt = some;
+        }
+        None => {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("No swap found for this reply"),
+            }));
+        }
+    }
+
+    let mut rsp = Response::new().add_attribute("pool_id", pool_id.clone());
+    match result {
+        ContractResult::Ok(_) => {
+            let config = CONFIG.load(deps.storage)?;
+            let current_fury_balance: BalanceResponse = deps.querier.query_wasm_smart(
+                config.minting_contract_address,
+                &Cw20QueryMsg::Balance {
+                    address: env.contract.address.to_string()
+                },
+            )?;
+            let balance_gained = current_fury_balance.balance
+                .checked_sub(attempt.balance_pre_swap)
+                .unwrap_or_default();
+            if balance_gained < attempt.min_fury_received {
+                return Err(ContractError::Std(StdError::GenericErr {
+                    msg: String::from("Swap returned less FURY than the minimum"),
+                }));
+            }
+            // ((Balance gained * 10_000) / Amount In UST Swapped)
+            // (poolcollection * exchange rate)/10_000 at time of use
+            let exchange_rate = balance_gained
+                .checked_mul(Uint128::from(10000u128))
+                .map_err(StdError::overflow)?
+                .checked_div(attempt.ust_amount)
+                .unwrap_or_default();
+            track_pool_obligations(deps.storage, &game_id, &pool_id, |storage| {
+                SWAP_BALANCE_INFO.save(
+                    storage,
+                    pool_id.clone(),
+                    &SwapBalanceDetails {
+                        balance_pre_swap: attempt.balance_pre_swap,
+                        balance_post_swap: current_fury_balance.balance,
+                        exchange_rate,
+                        ust_amount_swapped: attempt.ust_amount,
+                        ust_for_rake: attempt.ust_for_rake,
+                    },
+                )
+            })?;
+            let ust_amount = attempt.ust_amount;
+            attempt.status = SwapStatus::Succeeded;
+            attempt.fury_received = balance_gained;
+            update_pool_ledger(deps.storage, &game_id, &pool_id, |ledger| {
+                ledger.swapped += ust_amount;
+            })?;
+            rsp = rsp
+                .add_attribute("swap_status", "succeeded")
+                .add_attribute("fury_balance_gained", balance_gained.to_string())
+                .add_attribute("exchange_rate_recieved", exchange_rate.to_string());
+        }
+        ContractResult::Err(err) => {
+            attempt.status = SwapStatus::Failed;
+            attempt.error = Some(err);
+            rsp = rsp.add_attribute("swap_status", "failed");
+        }
+    }
+    SWAP_HISTORY.save(deps.storage, (&*game_id, &*pool_id), &history)?;
+    return Ok(rsp);
 }
 
 pub fn execute_sweep(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     funds_to_send: Vec<Coin>) -> Result<Response, ContractError> {
     let state = CONFIG.load(deps.storage)?;
 
-    if info.sender != state.admin_address {
-        return Err(ContractError::Unauthorized { invoker: info.sender.clone().to_string() });
+    assert_treasury(deps.as_ref(), &info.sender)?;
+    // Only what is left over the pool obligations can be swept
+    let obligations = query_pool_obligations(deps.storage)?;
+    for coin in funds_to_send.iter() {
+        let mut owed = Uint128::zero();
+        for obligation in obligations.iter() {
+            if obligation.0 == Denom::Native(coin.denom.clone()) {
+                owed = obligation.1;
+            }
+        }
+        if owed.is_zero() {
+            continue;
+        }
+        let balance = deps.querier.query_balance(env.contract.address.clone(), coin.denom.clone())?.amount;
+        if balance.checked_sub(owed).unwrap_or_default() < coin.amount {
+            return Err(ContractError::Std(StdError::GenericErr {
+                msg: String::from("Cannot sweep funds backing unclaimed rewards and refunds"),
+            }));
+        }
     }
     let r = CosmosMsg::Bank(BankMsg::Send {
         to_address: state.platform_fees_collector_wallet.to_string(),
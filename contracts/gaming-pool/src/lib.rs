@@ -4,9 +4,15 @@ pub mod allowances;
 pub mod contract;
 pub mod enumerable;
 mod error;
+pub mod events;
+pub mod migrations;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mock_proxy;
 pub mod msg;
+pub mod oracle;
 pub mod state;
 mod testing;
+mod multitest;
 mod execute;
 mod query;
 
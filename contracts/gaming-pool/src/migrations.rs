@@ -0,0 +1,202 @@
+use cosmwasm_std::{Addr, DepsMut, from_slice, Order, StdError, StdResult, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cw0::Expiration;
+use cw20::Denom;
+use cw_storage_plus::{Item, Map};
+
+use crate::ContractError;
+use crate::contract::INITIAL_TEAM_RANK;
+use crate::execute::{add_asset_amount, leaderboard_key};
+use crate::query::{may_load_pool_params, pool_obligations};
+use crate::state::{ADMIN, Config, CONFIG, GAME_DETAILS, GAME_OPERATOR, GAMER_CLAIMABLE_POOLS, GAMER_REFUNDABLE_POOLS, GameDetails, pools, pool_teams, POOL_LEADERBOARD, POOL_LEDGERS, POOL_OBLIGATIONS,
+                   POOL_REWARD_DETAILS, POOL_TYPE_DETAILS, PoolDetails, PoolLedger, PoolRewardDetails, PoolTeamDetails, SWAP_BALANCE_INFO, SwapBalanceDetails,
+                   TREASURY};
+
+// Storage layout used up to 0.9.1. Pools were keyed by pool id alone, teams by
+// (pool id, gamer), and the reward total and swap info of a pool were both saved
+// under "current_reward_for_pool" so whichever was written last survived.
+pub const LEGACY_LAYOUT_VERSION: &str = "0.9.1";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyConfig {
+    pub admin_address: Addr,
+    pub minting_contract_address: Addr,
+    pub platform_fees_collector_wallet: Addr,
+    pub astro_proxy_address: Addr,
+    pub platform_fee: Uint128,
+    pub transaction_fee: Uint128,
+    pub game_id: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LegacyGameDetails {
+    pub game_id: String,
+    pub game_status: u64,
+}
+
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+pub const LEGACY_GAME_DETAILS: Map<String, LegacyGameDetails> = Map::new("game_details");
+pub const LEGACY_POOL_DETAILS: Map<String, PoolDetails> = Map::new("pool_details");
+pub const LEGACY_POOL_TEAM_DETAILS: Map<(&str, &str), Vec<PoolTeamDetails>> = Map::new("pool_team_details");
+pub const LEGACY_CURRENT_REWARD_FOR_POOL: Map<String, Uint128> = Map::new("current_reward_for_pool");
+
+// Versions are compared as dotted numbers, so 0.10.0 is newer than 0.9.1
+pub fn parse_version(version: &str) -> StdResult<Vec<u64>> {
+    let mut parts = Vec::new();
+    for part in version.split('.') {
+        match part.parse::<u64>() {
+            Ok(number) => parts.push(number),
+            Err(_) => {
+                return Err(StdError::GenericErr {
+                    msg: format!("Invalid contract version {}", version),
+                });
+            }
+        }
+    }
+    return Ok(parts);
+}
+
+// Rewrites every record of the legacy layout into the current one
+pub fn migrate_legacy_layout(mut deps: DepsMut) -> Result<(), ContractError> {
+    // The admin kept in the config takes all the roles
+    let legacy_config = LEGACY_CONFIG.load(deps.storage)?;
+    ADMIN.set(deps.branch(), Some(legacy_config.admin_address.clone()))?;
+    GAME_OPERATOR.set(deps.branch(), Some(legacy_config.admin_address.clone()))?;
+    TREASURY.set(deps.branch(), Some(legacy_config.admin_address))?;
+    // The admin and the game id no longer live in the config
+    CONFIG.save(deps.storage, &Config {
+        minting_contract_address: legacy_config.minting_contract_address,
+        platform_fees_collector_wallet: legacy_config.platform_fees_collector_wallet,
+        astro_proxy_address: legacy_config.astro_proxy_address,
+        platform_fee: legacy_config.platform_fee,
+        transaction_fee: legacy_config.transaction_fee,
+    })?;
+
+    // Games had no entry close time, they stay open until locked
+    let legacy_games = LEGACY_GAME_DETAILS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|g| Ok(g?.1))
+        .collect::<StdResult<Vec<LegacyGameDetails>>>()?;
+    for game in legacy_games {
+        GAME_DETAILS.save(
+            deps.storage,
+            game.game_id.clone(),
+            &GameDetails {
+                game_id: game.game_id,
+                game_status: game.game_status,
+                entry_close_time: Expiration::Never {},
+            },
+        )?;
+    }
+
+    // Old and new keys share the namespaces, so all the old records are read
+    // before any of them is rewritten
+    let legacy_teams = LEGACY_POOL_TEAM_DETAILS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|t| Ok(t?.1))
+        .collect::<StdResult<Vec<Vec<PoolTeamDetails>>>>()?;
+    for teams in legacy_teams.iter() {
+        if teams.is_empty() {
+            continue;
+        }
+        let team = teams[0].clone();
+        LEGACY_POOL_TEAM_DETAILS.remove(deps.storage, (&team.pool_id, &team.gamer_address));
+        pool_teams().save(deps.storage, (&team.game_id, &team.pool_id, &team.gamer_address), teams)?;
+        // Claims only go through the pools listed for the gamer
+        if teams.iter().any(|t| !t.reward_amount.is_zero() && !t.claimed_reward) {
+            GAMER_CLAIMABLE_POOLS.save(deps.storage, (&team.gamer_address, &team.pool_id), &team.game_id)?;
+        }
+        for ranked in teams.iter().filter(|t| t.team_rank != INITIAL_TEAM_RANK) {
+            POOL_LEADERBOARD.save(
+                deps.storage,
+                (&team.game_id, &team.pool_id, &leaderboard_key(ranked)),
+                &(ranked.gamer_address.clone(), ranked.team_id.clone()),
+            )?;
+        }
+    }
+
+    let legacy_pools = LEGACY_POOL_DETAILS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|p| Ok(p?.1))
+        .collect::<StdResult<Vec<PoolDetails>>>()?;
+    for pool in legacy_pools {
+        LEGACY_POOL_DETAILS.remove(deps.storage, pool.pool_id.clone());
+        pools().save(deps.storage, (&pool.game_id, &pool.pool_id), &pool)?;
+
+        // Pools had no ledger, it is rebuilt from the teams and the pool type
+        let mut ledger = PoolLedger::default();
+        let pool_type = POOL_TYPE_DETAILS.may_load(deps.storage, pool.pool_type.clone())?;
+        match pool_type {
+            Some(pool_type) => {
+                ledger.collected = pool_type.pool_fee * Uint128::from(pool.current_teams_count);
+            }
+            None => {}
+        }
+        for team in legacy_teams.iter().flatten() {
+            if team.game_id != pool.game_id || team.pool_id != pool.pool_id {
+                continue;
+            }
+            if team.claimed_reward {
+                ledger.rewards_claimed += team.reward_amount;
+            }
+            if team.claimed_refund {
+                ledger.refunds_claimed += team.refund_amount;
+            } else if pool.pool_refund_status {
+                GAMER_REFUNDABLE_POOLS.save(deps.storage, (&team.gamer_address, &team.pool_id), &team.game_id)?;
+            }
+        }
+
+        // Swap info moves to its own namespace, a reward total becomes the
+        // pool reward details. Ranks paid out before the migration are unknown
+        let legacy_key = LEGACY_CURRENT_REWARD_FOR_POOL.key(pool.pool_id.clone());
+        let legacy_value = deps.storage.get(&legacy_key);
+        match legacy_value {
+            Some(raw) => {
+                match from_slice::<SwapBalanceDetails>(&raw) {
+                    Ok(swap_info) => {
+                        SWAP_BALANCE_INFO.save(deps.storage, pool.pool_id.clone(), &swap_info)?;
+                        ledger.swapped = swap_info.ust_amount_swapped;
+                        if pool.rewards_distributed {
+                            ledger.rake_paid = swap_info.ust_for_rake;
+                        }
+                    }
+                    Err(_) => {
+                        let reward_assigned: Uint128 = from_slice(&raw)?;
+                        ledger.rewards_assigned = reward_assigned;
+                        POOL_REWARD_DETAILS.save(
+                            deps.storage,
+                            (&pool.game_id, &pool.pool_id),
+                            &PoolRewardDetails {
+                                reward_assigned,
+                                ranks_assigned: vec![],
+                            },
+                        )?;
+                    }
+                }
+                LEGACY_CURRENT_REWARD_FOR_POOL.remove(deps.storage, pool.pool_id.clone());
+            }
+            None => {}
+        }
+        POOL_LEDGERS.save(deps.storage, (&pool.game_id, &pool.pool_id), &ledger)?;
+    }
+
+    // The running obligation totals start from what every pool owes now.
+    // Pools whose pool type is gone cannot be valued and are left out
+    let all_pools = pools()
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|p| Ok(p?.1))
+        .collect::<StdResult<Vec<PoolDetails>>>()?;
+    let mut totals: Vec<(Denom, Uint128)> = Vec::new();
+    for pool in all_pools {
+        if may_load_pool_params(deps.storage, &pool.game_id, &pool.pool_id)?.is_none() {
+            continue;
+        }
+        for (asset, owed) in pool_obligations(deps.storage, &pool)? {
+            add_asset_amount(&mut totals, asset, owed);
+        }
+    }
+    POOL_OBLIGATIONS.save(deps.storage, &totals)?;
+    return Ok(());
+}
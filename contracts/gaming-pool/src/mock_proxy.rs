@@ -0,0 +1,121 @@
+use astroport::asset::{Asset, AssetInfo};
+use cosmwasm_std::{BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+                   Response, StdError, StdResult, to_binary, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+// Stand-in for the astroport proxy, with a fixed FURY/UST price and swap fee.
+// Only built for native targets, it is meant to be stored in a cw-multi-test App.
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MockProxyConfig {
+    pub fury_token_address: String,
+    /// FURY given for one UST, both in micro units
+    pub fury_per_ust: Uint128,
+    /// UST charged by QueryPlatformFees for any swap
+    pub swap_fee: Uint128,
+}
+
+pub const MOCK_PROXY_CONFIG: Item<MockProxyConfig> = Item::new("mock_proxy_config");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MockProxyExecuteMsg {
+    Swap {
+        offer_asset: Asset,
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+        to: Option<String>,
+    },
+}
+
+/// Answers the same queries as the proxy, see ProxyQueryMsgs and QueryMsgSimulation
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MockProxyQueryMsg {
+    GetFuryEquivalentToUst { ust_count: Uint128 },
+    GetUstEquivalentToFury { fury_count: Uint128 },
+    QueryPlatformFees { msg: Binary },
+}
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: MockProxyConfig,
+) -> StdResult<Response> {
+    if msg.fury_per_ust.is_zero() {
+        return Err(StdError::generic_err("FURY price cannot be zero"));
+    }
+    MOCK_PROXY_CONFIG.save(deps.storage, &msg)?;
+    Ok(Response::default())
+}
+
+// Swaps at the configured price. FURY is pulled with the allowance the
+// sender gave, UST is paid out of the proxy's own bank balance
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: MockProxyExecuteMsg,
+) -> StdResult<Response> {
+    let config = MOCK_PROXY_CONFIG.load(deps.storage)?;
+    match msg {
+        MockProxyExecuteMsg::Swap { offer_asset, to, .. } => {
+            let receiver = to.unwrap_or(info.sender.to_string());
+            let mut messages: Vec<CosmosMsg> = Vec::new();
+            match offer_asset.info {
+                AssetInfo::NativeToken { denom: _ } => {
+                    let fury_amount = offer_asset.amount.multiply_ratio(config.fury_per_ust, 1u128);
+                    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                        contract_addr: config.fury_token_address,
+                        msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                            recipient: receiver,
+                            amount: fury_amount,
+                        })?,
+                        funds: vec![],
+                    }));
+                }
+                AssetInfo::Token { contract_addr } => {
+                    messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                        contract_addr: contract_addr.to_string(),
+                        msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                            owner: info.sender.to_string(),
+                            recipient: env.contract.address.to_string(),
+                            amount: offer_asset.amount,
+                        })?,
+                        funds: vec![],
+                    }));
+                    let ust_amount = offer_asset.amount.multiply_ratio(1u128, config.fury_per_ust);
+                    if !ust_amount.is_zero() {
+                        messages.push(CosmosMsg::Bank(BankMsg::Send {
+                            to_address: receiver,
+                            amount: vec![Coin {
+                                denom: "uusd".to_string(),
+                                amount: ust_amount,
+                            }],
+                        }));
+                    }
+                }
+            }
+            Ok(Response::new()
+                .add_attribute("action", "swap")
+                .add_messages(messages))
+        }
+    }
+}
+
+pub fn query(deps: Deps, _env: Env, msg: MockProxyQueryMsg) -> StdResult<Binary> {
+    let config = MOCK_PROXY_CONFIG.load(deps.storage)?;
+    match msg {
+        MockProxyQueryMsg::GetFuryEquivalentToUst { ust_count } => {
+            to_binary(&ust_count.multiply_ratio(config.fury_per_ust, 1u128))
+        }
+        MockProxyQueryMsg::GetUstEquivalentToFury { fury_count } => {
+            to_binary(&fury_count.multiply_ratio(1u128, config.fury_per_ust))
+        }
+        MockProxyQueryMsg::QueryPlatformFees { msg: _ } => to_binary(&config.swap_fee),
+    }
+}
@@ -4,10 +4,11 @@ use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cw20::{Cw20ReceiveMsg, Logo};
+use cw0::{Duration, Expiration};
+use cw20::{Cw20ReceiveMsg, Denom, Logo};
+use cw3::Vote;
 
-use crate::ContractError;
-use crate::state::{GameResult, SwapBalanceDetails, WalletPercentage};
+use crate::state::{PayoutTier, TeamRanking, WalletPercentage};
 
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
 pub struct InstantiateMarketingInfo {
@@ -25,7 +26,6 @@ pub struct InstantiateMsg {
     pub transaction_fee: Uint128,
     pub astro_proxy_address: String,
     pub platform_fee: Uint128,
-    pub game_id: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -34,6 +34,22 @@ pub struct MigrateMsg {}
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
+    ProposeNewAdmin {
+        new_admin: String,
+    },
+    AcceptAdminRole {},
+    SetGameOperator {
+        game_operator: String,
+    },
+    SetTreasury {
+        treasury: String,
+    },
+    SetClubStakingContract {
+        club_staking_contract: String,
+    },
+    SetActivationStatus {
+        is_active: bool,
+    },
     SetPlatformFeeWallets {
         wallet_percentages: Vec<WalletPercentage>
     },
@@ -44,57 +60,190 @@ pub enum ExecuteMsg {
         max_teams_for_pool: u32,
         max_teams_for_gamer: u32,
         wallet_percentages: Vec<WalletPercentage>,
+        payout_tiers: Vec<PayoutTier>,
+        entry_asset: Option<Denom>,
+        withdrawal_fee: Option<Uint128>,
+    },
+    /// Changes the parameters of an existing pool type for the pools created
+    /// from now on. The entry asset cannot change
+    UpdatePoolType {
+        pool_type: String,
+        pool_fee: Uint128,
+        min_teams_for_pool: u32,
+        max_teams_for_pool: u32,
+        max_teams_for_gamer: u32,
+        wallet_percentages: Vec<WalletPercentage>,
+        payout_tiers: Vec<PayoutTier>,
+        withdrawal_fee: Option<Uint128>,
+    },
+    /// Stops new pools of the type from being created
+    DisablePoolType {
+        pool_type: String,
+    },
+    CreateGame {
+        game_id: String,
+        entry_close_time: Expiration,
+    },
+    SetEntryCloseTime {
+        game_id: String,
+        entry_close_time: Expiration,
+    },
+    CancelGame {
+        game_id: String,
+    },
+    LockGame {
+        game_id: String,
     },
-    CancelGame {},
-    LockGame {},
+    /// The pool is private when invitees or a join code hash are given,
+    /// the hash is the sha256 of the code gamers have to bid with
     CreatePool {
-        pool_type: String
+        game_id: String,
+        pool_type: String,
+        invitees: Option<Vec<String>>,
+        join_code_hash: Option<Binary>,
     },
+    /// Registers the payout address and the relayer of the sender, leaving
+    /// one out removes it
+    SetClaimSettings {
+        payout_address: Option<String>,
+        relayer: Option<String>,
+    },
+    /// Claims from the given pools, or from the pools with unclaimed
+    /// balances when none are given, at most limit pools at a time
+    /// FURY rewards are staked on auto_stake_club instead of being sent
     ClaimReward {
-        gamer: String
+        gamer: String,
+        pool_ids: Option<Vec<String>>,
+        limit: Option<u32>,
+        auto_stake_club: Option<String>,
     },
     ClaimRefund {
         gamer: String,
         max_spread: Option<Decimal>,
+        pool_ids: Option<Vec<String>>,
+        limit: Option<u32>,
+    },
+    /// Approves a pool type for pools created by players
+    SetPoolTemplate {
+        pool_type: String,
+        min_pool_size: u32,
+        max_pool_size: u32,
+        creator_rake_percentage: u32,
     },
-    GamePoolRewardDistribute {
+    /// Opens a pool of an approved pool type, the sender is its creator. Each
+    /// address can only open a few pools per game
+    CreateUserPool {
+        game_id: String,
+        pool_type: String,
+        name: String,
+        pool_size: u32,
+    },
+    /// Invites or uninvites gamers to a private pool, only its creator can
+    /// change them and only until the game is locked
+    UpdatePoolInvitees {
+        game_id: String,
         pool_id: String,
-        game_winners: Vec<GameResult>,
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Replaces the team id of a team of the sender while the game is open
+    UpdateTeam {
+        game_id: String,
+        pool_id: String,
+        old_team_id: String,
+        new_team_id: String,
+    },
+    /// Takes a team of the sender out of a pool while the game is open,
+    /// the pool fee is refunded minus the pool type's withdrawal fee
+    WithdrawTeam {
+        game_id: String,
+        pool_id: String,
+        team_id: String,
+        max_spread: Option<Decimal>,
+    },
+    SetResultDisputeParams {
+        challenge_period: Duration,
+        oracles: Vec<String>,
+        veto_threshold: u32,
+    },
+    ProposeGameResults {
+        game_id: String,
+        pool_id: String,
+        game_winners: Vec<TeamRanking>,
         is_final_batch: bool,
-        ust_for_rake: Uint128,
+    },
+    /// Drops the pending results of a pool so they can be proposed again
+    WithdrawGameResults {
+        game_id: String,
+        pool_id: String,
+    },
+    VoteGameResults {
+        game_id: String,
+        pool_id: String,
+        vote: Vote,
+    },
+    FinalizeGameResults {
+        game_id: String,
+        pool_id: String,
     },
     GamePoolBidSubmitCommand {
         gamer: String,
+        game_id: String,
         pool_type: String,
         pool_id: String,
         team_id: String,
         amount: Uint128,
         max_spread: Option<Decimal>,
-
+        /// Pre-image of the join code hash of a private pool
+        join_code: Option<String>,
     },
+    GamePoolBidSubmitAnyPool {
+        gamer: String,
+        game_id: String,
+        pool_type: String,
+        team_id: String,
+        amount: Uint128,
+        max_spread: Option<Decimal>,
+    },
+    Receive(Cw20ReceiveMsg),
     Sweep { funds: Vec<Coin> },
+    /// Swaps the UST collected by a pool for FURY. The swap is reverted when
+    /// it brings in less than min_fury_received, and can be sent again if
+    /// the proxy fails it
     Swap {
         amount: Uint128,
+        game_id: String,
         pool_id: String,
         max_spread: Option<Decimal>,
+        min_fury_received: Option<Uint128>,
     },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
+    Roles {},
+    ActivationStatus {},
     PoolTeamDetails {
+        game_id: String,
         pool_id: String,
         user: String,
     },
     PoolDetails {
+        game_id: String,
         pool_id: String,
     },
     PoolTypeDetails {
         pool_type: String,
     },
-    AllPoolTypeDetails {},
-    AllTeams { users: Vec<String> },
+    AllPoolTypeDetails {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    AllTeams {
+        game_id: String,
+        users: Vec<String>,
+    },
     QueryReward {
         gamer: String
     },
@@ -102,20 +251,40 @@ pub enum QueryMsg {
         gamer: String,
     },
     QueryGameResult {
+        game_id: String,
         gamer: String,
         pool_id: String,
         team_id: String,
     },
-    GameDetails {},
+    GameDetails {
+        game_id: String,
+    },
+    AllGames {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     PoolTeamDetailsWithTeamId {
+        game_id: String,
         pool_id: String,
         team_id: String,
         gamer: String,
     },
-    AllPoolsInGame {},
+    AllPoolsInGame {
+        game_id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     PoolCollection {
+        game_id: String,
+        pool_id: String,
+    },
+    /// Funds collected, swapped and paid out by a pool
+    PoolLedger {
+        game_id: String,
         pool_id: String,
     },
+    /// Contract balances against what all the pools still owe
+    SolvencyReport {},
     GetTeamCountForUserInPoolType {
         gamer: String,
         game_id: String,
@@ -124,9 +293,46 @@ pub enum QueryMsg {
     SwapInfo {
         pool_id: String
     },
+    /// Every swap sent for the pool with its status, oldest first
+    SwapHistory {
+        game_id: String,
+        pool_id: String,
+    },
     GetTotalFees {
         amount: Uint128
     },
+    PendingGameResults {
+        game_id: String,
+        pool_id: String,
+    },
+    ResultDisputeConfig {},
+    PoolTemplate {
+        pool_type: String,
+    },
+    /// Creator, name and size of a pool created by a player, null otherwise
+    UserPool {
+        game_id: String,
+        pool_id: String,
+    },
+    /// Teams of a pool ordered by rank, then by points
+    PoolLeaderboard {
+        game_id: String,
+        pool_id: String,
+        /// Position of the last entry of the previous page
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
+    GamerStats {
+        gamer: String,
+    },
+    /// Invitees and join code hash of a private pool, null for public pools
+    PoolAccess {
+        game_id: String,
+        pool_id: String,
+    },
+    ClaimSettings {
+        gamer: String,
+    },
 }
 
 
@@ -134,14 +340,50 @@ pub enum QueryMsg {
 #[serde(rename_all = "snake_case")]
 pub enum ReceivedMsg {
     GamePoolBidSubmit(GamePoolBidSubmitCommand),
+    GamePoolBidSubmitAnyPool(GamePoolBidSubmitAnyPoolCommand),
+}
+
+/// Commands of the club staking contract, sent along with FURY
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClubStakingReceivedMsg {
+    StakeOnAClub(StakeOnAClubCommand),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakeOnAClubCommand {
+    pub staker: String,
+    pub club_name: String,
+    pub auto_stake: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct GamePoolBidSubmitCommand {
     pub gamer: String,
+    pub game_id: String,
     pub pool_type: String,
     pub pool_id: String,
     pub team_id: String,
+    pub max_spread: Option<Decimal>,
+    pub join_code: Option<String>,
+}
+
+/// Same as GamePoolBidSubmitCommand but the contract picks the pool,
+/// opening a new one when all pools of the type are full
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GamePoolBidSubmitAnyPoolCommand {
+    pub gamer: String,
+    pub game_id: String,
+    pub pool_type: String,
+    pub team_id: String,
+    pub max_spread: Option<Decimal>,
+}
+
+/// Returned as response data when the contract assigns the pool
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BidSubmitResponse {
+    pub game_id: String,
+    pub pool_id: String,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -201,3 +443,56 @@ pub enum QueryMsgSimulation {
 pub struct BalanceResponse {
     pub balance: Uint128,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RolesResponse {
+    pub admin: Option<String>,
+    pub pending_admin: Option<String>,
+    pub game_operator: Option<String>,
+    pub treasury: Option<String>,
+}
+
+/// Balance the contract holds of an asset and the part of it the pools still owe
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AssetSolvency {
+    pub asset: Denom,
+    pub balance: Uint128,
+    pub obligations: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SolvencyReport {
+    pub assets: Vec<AssetSolvency>,
+    pub is_solvent: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LeaderboardEntry {
+    /// 1 for the first team of the leaderboard
+    pub position: u32,
+    pub gamer_address: String,
+    pub team_id: String,
+    pub team_rank: u64,
+    pub team_points: u64,
+    pub reward_amount: Uint128,
+}
+
+/// Totals of an asset, net_profit or net_loss is set depending on whether
+/// the gamer won and got back more than they spent
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GamerAssetPnl {
+    pub asset: Denom,
+    pub spent: Uint128,
+    pub won: Uint128,
+    pub refunded: Uint128,
+    pub net_profit: Uint128,
+    pub net_loss: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct GamerStatsResponse {
+    pub pools_entered: u64,
+    pub teams_entered: u64,
+    pub wins: u64,
+    pub assets: Vec<GamerAssetPnl>,
+}
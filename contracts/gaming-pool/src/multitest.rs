@@ -0,0 +1,1812 @@
+#[cfg(test)]
+mod tests {
+    use anyhow::Result as AnyResult;
+    use cosmwasm_std::{Addr, Api, Binary, BlockInfo, coin, Decimal, Empty, Event, StdError, Storage, to_binary, Uint128};
+    use cw0::{Duration, Expiration};
+    use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, Denom, MinterResponse};
+    use cw_multi_test::{App, AppBuilder, AppResponse, ContractWrapper, CustomHandler, Executor};
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+    use sha2::{Digest, Sha256};
+
+    use crate::contract::{execute, instantiate, query, reply, INITIAL_TEAM_RANK, MAX_POOL_NAME_LENGTH, MAX_USER_POOLS_PER_GAME};
+    use crate::ContractError;
+    use crate::mock_proxy;
+    use crate::msg::{AssetSolvency, ExecuteMsg, GamePoolBidSubmitCommand, GamerAssetPnl, GamerStatsResponse, InstantiateMsg,
+                     LeaderboardEntry, QueryMsg, ReceivedMsg, SolvencyReport};
+    use crate::state::{ClaimSettings, PayoutTier, PoolAccess, PoolDetails, PoolLedger, PoolTeamDetails, SwapAttempt, SwapBalanceDetails, SwapStatus, TeamRanking, UserPoolDetails, WalletPercentage};
+
+    const ADMIN: &str = "admin11111";
+    const FEE_WALLET: &str = "feewallet11111";
+    const RAKE_WALLET: &str = "rake11111";
+    const GAMER_1: &str = "gamer11111";
+    const GAMER_2: &str = "gamer22222";
+
+    // compute_tax asks the Terra treasury for the tax rate and cap,
+    // one answer with both fields and no tax serves either query
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+    struct NoTaxResponse {
+        rate: Decimal,
+        cap: Uint128,
+    }
+
+    struct NoTaxTreasury;
+
+    impl CustomHandler for NoTaxTreasury {
+        fn execute(
+            &self,
+            _api: &dyn Api,
+            _storage: &mut dyn Storage,
+            _block: &BlockInfo,
+            sender: Addr,
+            msg: Empty,
+        ) -> AnyResult<AppResponse> {
+            panic!("Unexpected custom exec msg {:?} from {:?}", msg, sender)
+        }
+
+        fn query(
+            &self,
+            _api: &dyn Api,
+            _storage: &dyn Storage,
+            _block: &BlockInfo,
+            _msg: Empty,
+        ) -> AnyResult<Binary> {
+            Ok(to_binary(&NoTaxResponse {
+                rate: Decimal::zero(),
+                cap: Uint128::zero(),
+            })?)
+        }
+    }
+
+    struct Suite {
+        app: App,
+        fury: Addr,
+        proxy: Addr,
+        gaming_pool: Addr,
+    }
+
+    // FURY token, a proxy swapping at 10 FURY per UST for a 5 UST fee,
+    // and a gaming pool charging 3% platform and 1% transaction fee
+    fn setup_suite() -> Suite {
+        let mut app: App = AppBuilder::new().with_custom(NoTaxTreasury).build();
+        let fury_code_id = app.store_code(Box::new(ContractWrapper::new(
+            cw20_base::contract::execute,
+            cw20_base::contract::instantiate,
+            cw20_base::contract::query,
+        )));
+        let proxy_code_id = app.store_code(Box::new(ContractWrapper::new(
+            mock_proxy::execute,
+            mock_proxy::instantiate,
+            mock_proxy::query,
+        )));
+        let gaming_pool_code_id = app.store_code(Box::new(
+            ContractWrapper::new(execute, instantiate, query).with_reply(reply),
+        ));
+
+        let fury = app.instantiate_contract(
+            fury_code_id,
+            Addr::unchecked(ADMIN),
+            &cw20_base::msg::InstantiateMsg {
+                name: "Fury".to_string(),
+                symbol: "FURY".to_string(),
+                decimals: 6,
+                initial_balances: vec![
+                    Cw20Coin { address: GAMER_1.to_string(), amount: Uint128::from(20000u128) },
+                    Cw20Coin { address: GAMER_2.to_string(), amount: Uint128::from(20000u128) },
+                ],
+                mint: Some(MinterResponse { minter: ADMIN.to_string(), cap: None }),
+                marketing: None,
+            },
+            &[],
+            "fury",
+            None,
+        ).unwrap();
+        let proxy = app.instantiate_contract(
+            proxy_code_id,
+            Addr::unchecked(ADMIN),
+            &mock_proxy::MockProxyConfig {
+                fury_token_address: fury.to_string(),
+                fury_per_ust: Uint128::from(10u128),
+                swap_fee: Uint128::from(5u128),
+            },
+            &[],
+            "astroport proxy",
+            None,
+        ).unwrap();
+        let gaming_pool = app.instantiate_contract(
+            gaming_pool_code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin_address: ADMIN.to_string(),
+                minting_contract_address: fury.to_string(),
+                platform_fees_collector_wallet: FEE_WALLET.to_string(),
+                transaction_fee: Uint128::from(100u128),
+                astro_proxy_address: proxy.to_string(),
+                platform_fee: Uint128::from(300u128),
+            },
+            &[],
+            "gaming pool",
+            None,
+        ).unwrap();
+
+        // The proxy pays out of its own FURY and UST
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            fury.clone(),
+            &Cw20ExecuteMsg::Mint { recipient: proxy.to_string(), amount: Uint128::from(100000u128) },
+            &[],
+        ).unwrap();
+        app.init_bank_balance(&proxy, vec![coin(10000, "uusd")]).unwrap();
+        app.init_bank_balance(&Addr::unchecked(GAMER_1), vec![coin(1000, "uusd")]).unwrap();
+        app.init_bank_balance(&Addr::unchecked(GAMER_2), vec![coin(1000, "uusd")]).unwrap();
+
+        // Pool fee of 1000 UST with a 100 UST withdrawal fee, the unswapped UST
+        // all goes to the rake wallet
+        let mut payout_tiers: Vec<PayoutTier> = Vec::new();
+        payout_tiers.push(PayoutTier { from_rank: 1u64, to_rank: 1u64, percentage: 5000u32 });
+        payout_tiers.push(PayoutTier { from_rank: 2u64, to_rank: 2u64, percentage: 3000u32 });
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            gaming_pool.clone(),
+            &ExecuteMsg::SetPoolTypeParams {
+                pool_type: "oneToTwo".to_string(),
+                pool_fee: Uint128::from(1000u128),
+                min_teams_for_pool: 2,
+                max_teams_for_pool: 10,
+                max_teams_for_gamer: 5,
+                wallet_percentages: vec![WalletPercentage {
+                    wallet_address: RAKE_WALLET.to_string(),
+                    wallet_name: "rake".to_string(),
+                    percentage: 100u32,
+                }],
+                payout_tiers,
+                entry_asset: None,
+                withdrawal_fee: Some(Uint128::from(100u128)),
+            },
+            &[],
+        ).unwrap();
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            gaming_pool.clone(),
+            &ExecuteMsg::CreateGame {
+                game_id: "Game001".to_string(),
+                entry_close_time: Expiration::Never {},
+            },
+            &[],
+        ).unwrap();
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            gaming_pool.clone(),
+            &ExecuteMsg::CreatePool {
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                invitees: None,
+                join_code_hash: None,
+            },
+            &[],
+        ).unwrap();
+        return Suite {
+            app,
+            fury,
+            proxy,
+            gaming_pool,
+        };
+    }
+
+    fn fury_balance(suite: &Suite, address: &str) -> Uint128 {
+        let rsp: BalanceResponse = suite.app.wrap().query_wasm_smart(
+            suite.fury.clone(),
+            &Cw20QueryMsg::Balance { address: address.to_string() },
+        ).unwrap();
+        return rsp.balance;
+    }
+
+    fn ust_balance(suite: &Suite, address: &str) -> Uint128 {
+        return suite.app.wrap().query_balance(address, "uusd").unwrap().amount;
+    }
+
+    fn approve(suite: &mut Suite, gamer: &str) -> AnyResult<AppResponse> {
+        suite.app.execute_contract(
+            Addr::unchecked(gamer),
+            suite.fury.clone(),
+            &Cw20ExecuteMsg::IncreaseAllowance {
+                spender: suite.gaming_pool.to_string(),
+                amount: Uint128::from(10000u128),
+                expires: None,
+            },
+            &[],
+        )
+    }
+
+    // 10000 FURY for the 1000 UST pool fee, and 40 UST of fees
+    fn submit_bid(suite: &mut Suite, gamer: &str, team_id: &str) -> AnyResult<AppResponse> {
+        return submit_bid_in_pool(suite, gamer, "1", team_id, None);
+    }
+
+    fn submit_bid_in_pool(
+        suite: &mut Suite,
+        gamer: &str,
+        pool_id: &str,
+        team_id: &str,
+        join_code: Option<&str>,
+    ) -> AnyResult<AppResponse> {
+        suite.app.execute_contract(
+            Addr::unchecked(gamer),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::GamePoolBidSubmitCommand {
+                gamer: gamer.to_string(),
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                pool_id: pool_id.to_string(),
+                team_id: team_id.to_string(),
+                amount: Uint128::from(10000u128),
+                max_spread: None,
+                join_code: join_code.map(String::from),
+            },
+            &[coin(40, "uusd")],
+        )
+    }
+
+    #[test]
+    fn test_bid_lock_swap_distribute_claim() {
+        let mut suite = setup_suite();
+        let gaming_pool = suite.gaming_pool.to_string();
+
+        // Each bid pulls the FURY in and swaps it to UST through the proxy
+        approve(&mut suite, GAMER_1).unwrap();
+        let rsp = submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        rsp.assert_event(&Event::new("wasm-team_entered")
+            .add_attribute("game_id", "Game001")
+            .add_attribute("pool_id", "1")
+            .add_attribute("gamer", GAMER_1)
+            .add_attribute("team_id", "Team001")
+            .add_attribute("amount", "10000"));
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(10000u128));
+        assert_eq!(fury_balance(&suite, &gaming_pool), Uint128::zero());
+        assert_eq!(fury_balance(&suite, suite.proxy.as_str()), Uint128::from(120000u128));
+        // 2 x (40 UST fees - 5 UST swap fee + 1000 UST from the swap)
+        assert_eq!(ust_balance(&suite, &gaming_pool), Uint128::from(2070u128));
+
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-game_locked").add_attribute("game_id", "Game001"));
+
+        // Swap 1800 of the 2000 UST collected back to FURY, the reply
+        // records the FURY the swap bought
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Swap {
+                amount: Uint128::from(1800u128),
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                max_spread: None,
+                min_fury_received: None,
+            },
+            &[],
+        ).unwrap();
+        let swap_info: SwapBalanceDetails = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::SwapInfo { pool_id: "1".to_string() },
+        ).unwrap();
+        assert_eq!(swap_info.balance_pre_swap, Uint128::zero());
+        assert_eq!(swap_info.balance_post_swap, Uint128::from(18000u128));
+        assert_eq!(swap_info.ust_for_rake, Uint128::from(200u128));
+        assert_eq!(swap_info.exchange_rate, Uint128::from(100000u128));
+
+        // No challenge period is configured, so results finalise straight away
+        let mut game_winners: Vec<TeamRanking> = Vec::new();
+        game_winners.push(TeamRanking {
+            gamer_address: GAMER_1.to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 1u64,
+            team_points: 300u64,
+        });
+        game_winners.push(TeamRanking {
+            gamer_address: GAMER_2.to_string(),
+            team_id: "Team002".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+        });
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetResultDisputeParams {
+                challenge_period: Duration::Time(0),
+                oracles: vec![],
+                veto_threshold: 1u32,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ProposeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                game_winners,
+                is_final_batch: true,
+            },
+            &[],
+        ).unwrap();
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::FinalizeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-team_result")
+            .add_attribute("gamer", GAMER_2)
+            .add_attribute("team_id", "Team002")
+            .add_attribute("team_rank", "2")
+            .add_attribute("amount", "5400"));
+        rsp.assert_event(&Event::new("wasm-rake_paid")
+            .add_attribute("game_id", "Game001")
+            .add_attribute("pool_id", "1")
+            .add_attribute("wallet_address", RAKE_WALLET)
+            .add_attribute("amount", "200"));
+        assert_eq!(ust_balance(&suite, RAKE_WALLET), Uint128::from(200u128));
+        let team: PoolTeamDetails = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolTeamDetailsWithTeamId {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                team_id: "Team001".to_string(),
+                gamer: GAMER_1.to_string(),
+            },
+        ).unwrap();
+        assert_eq!(team.reward_amount, Uint128::from(9000u128));
+
+        // 9000 FURY is worth 900 UST, the claim fee is 4% of that
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ClaimReward {
+                gamer: GAMER_1.to_string(),
+                pool_ids: None,
+                limit: None,
+                auto_stake_club: None,
+            },
+            &[coin(35, "uusd")],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::InsufficientFeesUst {});
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ClaimReward {
+                gamer: GAMER_1.to_string(),
+                pool_ids: None,
+                limit: None,
+                auto_stake_club: None,
+            },
+            &[coin(36, "uusd")],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-reward_claimed")
+            .add_attribute("game_id", "Game001")
+            .add_attribute("pool_id", "1")
+            .add_attribute("gamer", GAMER_1)
+            .add_attribute("team_id", "Team001")
+            .add_attribute("amount", "9000"));
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(19000u128));
+        assert_eq!(fury_balance(&suite, &gaming_pool), Uint128::from(9000u128));
+        assert_eq!(ust_balance(&suite, FEE_WALLET), Uint128::from(36u128));
+
+        // The claimed pool left the gamer's index
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ClaimReward {
+                gamer: GAMER_1.to_string(),
+                pool_ids: None,
+                limit: None,
+                auto_stake_club: None,
+            },
+            &[coin(36, "uusd")],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("No reward for this user"),
+        }));
+
+        // Only the reward not claimed yet is still owed
+        let report: SolvencyReport = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::SolvencyReport {},
+        ).unwrap();
+        assert_eq!(report.assets[0].obligations, Uint128::from(5400u128));
+        assert_eq!(report.assets[1].obligations, Uint128::zero());
+    }
+
+    #[test]
+    fn test_solvency_report_and_sweep_guard() {
+        let mut suite = setup_suite();
+        let gaming_pool = suite.gaming_pool.to_string();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+
+        // The 2000 UST collected is owed to the pool, only the fees left over can be swept
+        let report: SolvencyReport = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::SolvencyReport {},
+        ).unwrap();
+        assert!(report.is_solvent);
+        assert_eq!(report.assets[1], AssetSolvency {
+            asset: Denom::Native("uusd".to_string()),
+            balance: Uint128::from(2070u128),
+            obligations: Uint128::from(2000u128),
+        });
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Sweep { funds: vec![coin(71, "uusd")] },
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Cannot sweep funds backing unclaimed rewards and refunds"),
+        }));
+
+        // After the swap the pool owes the FURY bought and the UST kept for the rake
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Swap {
+                amount: Uint128::from(1800u128),
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                max_spread: None,
+                min_fury_received: None,
+            },
+            &[],
+        ).unwrap();
+        let ledger: PoolLedger = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolLedger {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+        ).unwrap();
+        assert_eq!(ledger.collected, Uint128::from(2000u128));
+        assert_eq!(ledger.swapped, Uint128::from(1800u128));
+        assert_eq!(ledger.rake_paid, Uint128::zero());
+
+        // 1805 UST went to the proxy with its swap fee
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Sweep { funds: vec![coin(66, "uusd")] },
+            &[],
+        ).unwrap_err();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Sweep { funds: vec![coin(65, "uusd")] },
+            &[],
+        ).unwrap();
+        assert_eq!(ust_balance(&suite, &gaming_pool), Uint128::from(200u128));
+        let report: SolvencyReport = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::SolvencyReport {},
+        ).unwrap();
+        assert!(report.is_solvent);
+        assert_eq!(report.assets, vec![
+            AssetSolvency {
+                asset: Denom::Cw20(suite.fury.clone()),
+                balance: Uint128::from(18000u128),
+                obligations: Uint128::from(18000u128),
+            },
+            AssetSolvency {
+                asset: Denom::Native("uusd".to_string()),
+                balance: Uint128::from(200u128),
+                obligations: Uint128::from(200u128),
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_withdraw_team_before_lock() {
+        let mut suite = setup_suite();
+        let gaming_pool = suite.gaming_pool.to_string();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+
+        // 900 of the 1000 UST pool fee comes back as 9000 FURY, the contract
+        // pays the 5 UST swap fee and the withdrawal fee goes to the fee wallet
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::WithdrawTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                team_id: "Team001".to_string(),
+                max_spread: None,
+            },
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-team_withdrawn")
+            .add_attribute("gamer", GAMER_1)
+            .add_attribute("team_id", "Team001")
+            .add_attribute("amount", "900"));
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(19000u128));
+        assert_eq!(ust_balance(&suite, FEE_WALLET), Uint128::from(100u128));
+        assert_eq!(ust_balance(&suite, &gaming_pool), Uint128::from(1065u128));
+        let pool: PoolDetails = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolDetails {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+        ).unwrap();
+        assert_eq!(pool.current_teams_count, 1u32);
+        let collection: Uint128 = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolCollection {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+        ).unwrap();
+        assert_eq!(collection, Uint128::from(1000u128));
+
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::WithdrawTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                team_id: "Team001".to_string(),
+                max_spread: None,
+            },
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("No team found for this user in the pool"),
+        }));
+
+        // Teams are held once the game is locked
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_2),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::WithdrawTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                team_id: "Team002".to_string(),
+                max_spread: None,
+            },
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Teams can only be withdrawn while the game is open"),
+        }));
+    }
+
+    #[test]
+    fn test_update_team_before_lock() {
+        let mut suite = setup_suite();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team002").unwrap();
+
+        // The gamer already has Team002 in the pool
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::UpdateTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                old_team_id: "Team001".to_string(),
+                new_team_id: "Team002".to_string(),
+            },
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("User already has this team in a pool of this pool type"),
+        }));
+
+        // Nor can the team take a team id the gamer holds in another pool of the same type
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CreatePool {
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                invitees: None,
+                join_code_hash: None,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.fury.clone(),
+            &Cw20ExecuteMsg::Mint { recipient: GAMER_1.to_string(), amount: Uint128::from(10000u128) },
+            &[],
+        ).unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team004", None).unwrap();
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::UpdateTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                old_team_id: "Team001".to_string(),
+                new_team_id: "Team004".to_string(),
+            },
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("User already has this team in a pool of this pool type"),
+        }));
+
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::UpdateTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                old_team_id: "Team001".to_string(),
+                new_team_id: "Team003".to_string(),
+            },
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-team_updated")
+            .add_attribute("team_id", "Team003")
+            .add_attribute("previous_team_id", "Team001"));
+        let team: PoolTeamDetails = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolTeamDetailsWithTeamId {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                team_id: "Team003".to_string(),
+                gamer: GAMER_1.to_string(),
+            },
+        ).unwrap();
+        assert_eq!(team.team_changes.len(), 1);
+        assert_eq!(team.team_changes[0].previous_team_id, "Team001".to_string());
+        assert_eq!(team.team_changes[0].changed_at, suite.app.block_info().time);
+
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::UpdateTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                old_team_id: "Team003".to_string(),
+                new_team_id: "Team001".to_string(),
+            },
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Teams can only be updated while the game is open"),
+        }));
+    }
+
+    #[test]
+    fn test_private_pool_invitees_and_join_code() {
+        let mut suite = setup_suite();
+        let join_code_hash = Binary::from(Sha256::digest(b"letmein").as_slice());
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CreatePool {
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                invitees: Some(vec![GAMER_2.to_string()]),
+                join_code_hash: Some(join_code_hash),
+            },
+            &[],
+        ).unwrap();
+
+        // Gamer 1 is not invited and needs the code
+        approve(&mut suite, GAMER_1).unwrap();
+        let err = submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team001", Some("guess")).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool is private and the gamer is not invited"),
+        }));
+        submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team001", Some("letmein")).unwrap();
+
+        // Only the creator manages the invitees
+        let update_invitees = ExecuteMsg::UpdatePoolInvitees {
+            game_id: "Game001".to_string(),
+            pool_id: "2".to_string(),
+            add: vec![],
+            remove: vec![GAMER_2.to_string()],
+        };
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &update_invitees,
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Unauthorized {
+            invoker: GAMER_1.to_string(),
+        });
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &update_invitees,
+            &[],
+        ).unwrap();
+        let pool_access: Option<PoolAccess> = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolAccess {
+                game_id: "Game001".to_string(),
+                pool_id: "2".to_string(),
+            },
+        ).unwrap();
+        assert_eq!(pool_access.unwrap().invitees, Vec::<Addr>::new());
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid_in_pool(&mut suite, GAMER_2, "2", "Team002", None).unwrap_err();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+    }
+
+    #[test]
+    fn test_user_pool_from_template_pays_creator_rake() {
+        let mut suite = setup_suite();
+        let create_user_pool = |pool_size: u32| ExecuteMsg::CreateUserPool {
+            game_id: "Game001".to_string(),
+            pool_type: "oneToTwo".to_string(),
+            name: "Friday league".to_string(),
+            pool_size,
+        };
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &create_user_pool(2),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool type is not open to player pools"),
+        }));
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetPoolTemplate {
+                pool_type: "oneToTwo".to_string(),
+                min_pool_size: 2,
+                max_pool_size: 4,
+                creator_rake_percentage: 10,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &create_user_pool(5),
+            &[],
+        ).unwrap_err();
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &create_user_pool(2),
+            &[],
+        ).unwrap();
+        let user_pool: Option<UserPoolDetails> = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::UserPool {
+                game_id: "Game001".to_string(),
+                pool_id: "2".to_string(),
+            },
+        ).unwrap();
+        assert_eq!(user_pool.unwrap().max_teams_for_pool, 2u32);
+
+        // The pool holds the 2 teams its creator chose, not the pool type's 10
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team001", None).unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid_in_pool(&mut suite, GAMER_2, "2", "Team002", None).unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team003", None).unwrap_err();
+
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Swap {
+                amount: Uint128::from(1800u128),
+                game_id: "Game001".to_string(),
+                pool_id: "2".to_string(),
+                max_spread: None,
+                min_fury_received: None,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetResultDisputeParams {
+                challenge_period: Duration::Time(0),
+                oracles: vec![],
+                veto_threshold: 1u32,
+            },
+            &[],
+        ).unwrap();
+        let mut game_winners: Vec<TeamRanking> = Vec::new();
+        game_winners.push(TeamRanking {
+            gamer_address: GAMER_1.to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 1u64,
+            team_points: 300u64,
+        });
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ProposeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "2".to_string(),
+                game_winners,
+                is_final_batch: true,
+            },
+            &[],
+        ).unwrap();
+        let creator_ust = ust_balance(&suite, GAMER_1);
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::FinalizeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "2".to_string(),
+            },
+            &[],
+        ).unwrap();
+
+        // 10% of the 200 UST rake goes to the creator
+        rsp.assert_event(&Event::new("wasm-rake_paid")
+            .add_attribute("wallet_address", GAMER_1)
+            .add_attribute("amount", "20"));
+        assert_eq!(ust_balance(&suite, RAKE_WALLET), Uint128::from(180u128));
+        assert_eq!(ust_balance(&suite, GAMER_1), creator_ust + Uint128::from(20u128));
+    }
+
+    #[test]
+    fn test_user_pool_name_and_per_game_cap() {
+        let mut suite = setup_suite();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetPoolTemplate {
+                pool_type: "oneToTwo".to_string(),
+                min_pool_size: 2,
+                max_pool_size: 4,
+                creator_rake_percentage: 10,
+            },
+            &[],
+        ).unwrap();
+        let create_user_pool = |name: &str| ExecuteMsg::CreateUserPool {
+            game_id: "Game001".to_string(),
+            pool_type: "oneToTwo".to_string(),
+            name: name.to_string(),
+            pool_size: 2,
+        };
+        let name_error = ContractError::Std(StdError::GenericErr {
+            msg: format!("Pool name must be 1 to {} characters long", MAX_POOL_NAME_LENGTH),
+        });
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &create_user_pool("   "),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), name_error);
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &create_user_pool(&"x".repeat(MAX_POOL_NAME_LENGTH + 1)),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), name_error);
+
+        // Each address only gets a few pools per game
+        for _ in 0..MAX_USER_POOLS_PER_GAME {
+            suite.app.execute_contract(
+                Addr::unchecked(GAMER_1),
+                suite.gaming_pool.clone(),
+                &create_user_pool("Friday league"),
+                &[],
+            ).unwrap();
+        }
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &create_user_pool("Friday league"),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Creator has opened the most pools allowed in this game"),
+        }));
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_2),
+            suite.gaming_pool.clone(),
+            &create_user_pool("Friday league"),
+            &[],
+        ).unwrap();
+    }
+
+    #[test]
+    fn test_update_and_disable_pool_type() {
+        let mut suite = setup_suite();
+        let update_pool_type = |pool_fee: u128, rake_percentages: Vec<u32>| ExecuteMsg::UpdatePoolType {
+            pool_type: "oneToTwo".to_string(),
+            pool_fee: Uint128::from(pool_fee),
+            min_teams_for_pool: 2,
+            max_teams_for_pool: 10,
+            max_teams_for_gamer: 5,
+            wallet_percentages: rake_percentages.into_iter().map(|percentage| WalletPercentage {
+                wallet_address: RAKE_WALLET.to_string(),
+                wallet_name: "rake".to_string(),
+                percentage,
+            }).collect(),
+            payout_tiers: vec![PayoutTier { from_rank: 1u64, to_rank: 1u64, percentage: 10000u32 }],
+            withdrawal_fee: None,
+        };
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &update_pool_type(2000, vec![60, 50]),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Rake percentages add up to more than 100"),
+        }));
+        // A pool type that can never reach its minimum is refused
+        let mut unreachable_minimum = update_pool_type(2000, vec![100]);
+        if let ExecuteMsg::UpdatePoolType { ref mut min_teams_for_pool, .. } = unreachable_minimum {
+            *min_teams_for_pool = 11;
+        }
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &unreachable_minimum,
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Minimum teams for pool cannot be more than the maximum"),
+        }));
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &update_pool_type(2000, vec![100]),
+            &[],
+        ).unwrap();
+
+        // Pool 1 keeps the 1000 UST fee it was created with, pool 2 takes 2000 UST
+        // and twice the 40 UST of fees
+        let create_pool = ExecuteMsg::CreatePool {
+            game_id: "Game001".to_string(),
+            pool_type: "oneToTwo".to_string(),
+            invitees: None,
+            join_code_hash: None,
+        };
+        suite.app.execute_contract(Addr::unchecked(ADMIN), suite.gaming_pool.clone(), &create_pool, &[]).unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        let err = submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team001", None).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::InsufficientFeesUst {});
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        let collection: Uint128 = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolCollection {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+        ).unwrap();
+        assert_eq!(collection, Uint128::from(1000u128));
+
+        // Disabled pool types open no new pools, the existing ones still take bids
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::DisablePoolType { pool_type: "oneToTwo".to_string() },
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-pool_type_disabled").add_attribute("pool_type", "oneToTwo"));
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &create_pool,
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool type is disabled"),
+        }));
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+    }
+
+    #[test]
+    fn test_pool_leaderboard_and_gamer_stats() {
+        let mut suite = setup_suite();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team003").unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team004").unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::WithdrawTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                team_id: "Team003".to_string(),
+                max_spread: None,
+            },
+            &[],
+        ).unwrap();
+
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Swap {
+                amount: Uint128::from(1800u128),
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                max_spread: None,
+                min_fury_received: None,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetResultDisputeParams {
+                challenge_period: Duration::Time(0),
+                oracles: vec![],
+                veto_threshold: 1u32,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ProposeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                game_winners: vec![
+                    TeamRanking {
+                        gamer_address: GAMER_1.to_string(),
+                        team_id: "Team001".to_string(),
+                        team_rank: 2u64,
+                        team_points: 200u64,
+                    },
+                    TeamRanking {
+                        gamer_address: GAMER_2.to_string(),
+                        team_id: "Team002".to_string(),
+                        team_rank: 1u64,
+                        team_points: 300u64,
+                    },
+                ],
+                is_final_batch: true,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::FinalizeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+            &[],
+        ).unwrap();
+
+        let leaderboard: Vec<LeaderboardEntry> = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolLeaderboard {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                start_after: None,
+                limit: None,
+            },
+        ).unwrap();
+        assert_eq!(leaderboard.len(), 3);
+        assert_eq!(leaderboard[0].position, 1u32);
+        assert_eq!(leaderboard[0].gamer_address, GAMER_2.to_string());
+        assert_eq!(leaderboard[0].reward_amount, Uint128::from(9000u128));
+        // Teams left out of the results come after the ranked ones
+        assert_eq!(leaderboard[2].position, 3u32);
+        assert_eq!(leaderboard[2].team_id, "Team004".to_string());
+        assert_eq!(leaderboard[2].team_rank, INITIAL_TEAM_RANK);
+        let leaderboard: Vec<LeaderboardEntry> = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolLeaderboard {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                start_after: Some(1u32),
+                limit: Some(1u32),
+            },
+        ).unwrap();
+        assert_eq!(leaderboard, vec![LeaderboardEntry {
+            position: 2u32,
+            gamer_address: GAMER_1.to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+            reward_amount: Uint128::from(5400u128),
+        }]);
+
+        // Two teams at 1000 UST each, 900 UST given back on withdrawal and
+        // the 5400 FURY of the second place worth 540 UST at the swap rate
+        let stats: GamerStatsResponse = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::GamerStats { gamer: GAMER_1.to_string() },
+        ).unwrap();
+        assert_eq!(stats.pools_entered, 1u64);
+        assert_eq!(stats.teams_entered, 2u64);
+        assert_eq!(stats.wins, 0u64);
+        assert_eq!(stats.assets, vec![
+            GamerAssetPnl {
+                asset: Denom::Native("uusd".to_string()),
+                spent: Uint128::from(2000u128),
+                won: Uint128::from(540u128),
+                refunded: Uint128::from(900u128),
+                net_profit: Uint128::zero(),
+                net_loss: Uint128::from(560u128),
+            },
+        ]);
+        let stats: GamerStatsResponse = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::GamerStats { gamer: GAMER_2.to_string() },
+        ).unwrap();
+        assert_eq!(stats.wins, 1u64);
+        assert_eq!(stats.assets[0].won, Uint128::from(900u128));
+    }
+
+    #[test]
+    fn test_claim_refund_by_pool_and_limit() {
+        let mut suite = setup_suite();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CreatePool {
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                invitees: None,
+                join_code_hash: None,
+            },
+            &[],
+        ).unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team001", None).unwrap();
+        // Covers the swap fee the proxy charges on each refund
+        suite.app.init_bank_balance(&suite.gaming_pool, vec![coin(4000, "uusd")]).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CancelGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+
+        // One pool at a time, each refunded once
+        let claim_pool_2 = ExecuteMsg::ClaimRefund {
+            gamer: GAMER_1.to_string(),
+            max_spread: None,
+            pool_ids: Some(vec!["2".to_string()]),
+            limit: None,
+        };
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &claim_pool_2,
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-refund_claimed")
+            .add_attribute("pool_id", "2")
+            .add_attribute("amount", "1000"));
+        assert!(!rsp.events.iter().any(|event| event.attributes.iter().any(|attr| {
+            attr.key == "pool_id" && attr.value == "1"
+        })));
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(10000u128));
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &claim_pool_2,
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Nothing to claim for this user in the pool"),
+        }));
+
+        // The rest is picked up from the pools left to claim
+        let claim_rest = ExecuteMsg::ClaimRefund {
+            gamer: GAMER_1.to_string(),
+            max_spread: None,
+            pool_ids: None,
+            limit: Some(1u32),
+        };
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &claim_rest,
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm-refund_claimed").add_attribute("pool_id", "1"));
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(20000u128));
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &claim_rest,
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("No refund for this user"),
+        }));
+    }
+
+    #[test]
+    fn test_relayer_claims_to_payout_address() {
+        let mut suite = setup_suite();
+        let cold_wallet = "coldwallet11111";
+        let relayer = "relayer11111";
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetClaimSettings {
+                payout_address: Some(cold_wallet.to_string()),
+                relayer: Some(relayer.to_string()),
+            },
+            &[],
+        ).unwrap();
+        let settings: ClaimSettings = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::ClaimSettings { gamer: GAMER_1.to_string() },
+        ).unwrap();
+        assert_eq!(settings.relayer, Some(Addr::unchecked(relayer)));
+
+        // A withdrawn team is refunded to the payout address as well
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team002").unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::WithdrawTeam {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                team_id: "Team002".to_string(),
+                max_spread: None,
+            },
+            &[],
+        ).unwrap();
+        assert_eq!(fury_balance(&suite, cold_wallet), Uint128::from(9000u128));
+        suite.app.init_bank_balance(&suite.gaming_pool, vec![coin(2000, "uusd")]).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CancelGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+
+        let claim = ExecuteMsg::ClaimRefund {
+            gamer: GAMER_1.to_string(),
+            max_spread: None,
+            pool_ids: None,
+            limit: None,
+        };
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_2),
+            suite.gaming_pool.clone(),
+            &claim,
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Unauthorized {
+            invoker: GAMER_2.to_string(),
+        });
+
+        // A relayer cannot claim for a gamer restricted on the FURY token
+        let restricted_until = suite.app.block_info().time.plus_seconds(1000);
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.fury.clone(),
+            &Cw20ExecuteMsg::SetWhiteListExpirationTimestamp { timestamp: restricted_until },
+            &[],
+        ).unwrap();
+        let restrict = |add_list: Vec<String>, remove_list: Vec<String>| Cw20ExecuteMsg::RestrictedWalletListUpdate {
+            add_list,
+            remove_list,
+        };
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.fury.clone(),
+            &restrict(vec![GAMER_1.to_string()], vec![]),
+            &[],
+        ).unwrap();
+        let err = suite.app.execute_contract(
+            Addr::unchecked(relayer),
+            suite.gaming_pool.clone(),
+            &claim,
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::UserIsRestricted {});
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.fury.clone(),
+            &restrict(vec![], vec![GAMER_1.to_string()]),
+            &[],
+        ).unwrap();
+
+        // The relayer claims, the refund and the fees go to the cold wallet
+        suite.app.execute_contract(
+            Addr::unchecked(relayer),
+            suite.gaming_pool.clone(),
+            &claim,
+            &[],
+        ).unwrap();
+        assert_eq!(fury_balance(&suite, cold_wallet), Uint128::from(19000u128));
+        assert_eq!(ust_balance(&suite, cold_wallet), Uint128::from(40u128));
+        assert_eq!(fury_balance(&suite, relayer), Uint128::zero());
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::zero());
+    }
+
+    #[test]
+    fn test_claim_reward_and_stake_on_a_club() {
+        let mut suite = setup_suite();
+        let club_staking_code_id = suite.app.store_code(Box::new(ContractWrapper::new(
+            club_staking::contract::execute,
+            club_staking::contract::instantiate,
+            club_staking::contract::query,
+        )));
+        let now = suite.app.block_info().time;
+        let club_staking = suite.app.instantiate_contract(
+            club_staking_code_id,
+            Addr::unchecked(ADMIN),
+            &club_staking::msg::InstantiateMsg {
+                admin_address: ADMIN.to_string(),
+                minting_contract_address: suite.fury.to_string(),
+                astro_proxy_address: suite.proxy.to_string(),
+                club_fee_collector_wallet: FEE_WALLET.to_string(),
+                club_reward_next_timestamp: now,
+                reward_periodicity: 24 * 60 * 60u64,
+                club_price: Uint128::from(1000u128),
+                bonding_duration: 5 * 60u64,
+                owner_release_locking_duration: 24 * 60 * 60u64,
+                platform_fees_collector_wallet: FEE_WALLET.to_string(),
+                platform_fees: Uint128::from(100u128),
+                transaction_fees: Uint128::from(30u128),
+                control_fees: Uint128::from(50u128),
+                max_bonding_limit_per_user: 10u64,
+            },
+            &[],
+            "club staking",
+            None,
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            club_staking.clone(),
+            &club_staking::msg::ExecuteMsg::SetGamingPoolContract {
+                gaming_pool_contract: suite.gaming_pool.to_string(),
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            club_staking.clone(),
+            &club_staking::msg::ExecuteMsg::AssignAClub {
+                buyer: RAKE_WALLET.to_string(),
+                seller: None,
+                club_name: "CLUB001".to_string(),
+                auto_stake: false,
+            },
+            &[],
+        ).unwrap();
+
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Swap {
+                amount: Uint128::from(1800u128),
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                max_spread: None,
+                min_fury_received: None,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetResultDisputeParams {
+                challenge_period: Duration::Time(0),
+                oracles: vec![],
+                veto_threshold: 1u32,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ProposeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                game_winners: vec![TeamRanking {
+                    gamer_address: GAMER_1.to_string(),
+                    team_id: "Team001".to_string(),
+                    team_rank: 1u64,
+                    team_points: 300u64,
+                }],
+                is_final_batch: true,
+            },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::FinalizeGameResults {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+            &[],
+        ).unwrap();
+
+        let claim = ExecuteMsg::ClaimReward {
+            gamer: GAMER_1.to_string(),
+            pool_ids: None,
+            limit: None,
+            auto_stake_club: Some("CLUB001".to_string()),
+        };
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &claim,
+            &[coin(36, "uusd")],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Club staking contract is not set"),
+        }));
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetClubStakingContract { club_staking_contract: club_staking.to_string() },
+            &[],
+        ).unwrap();
+
+        // The 9000 FURY reward ends up staked on the club, not in the wallet
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &claim,
+            &[coin(36, "uusd")],
+        ).unwrap();
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(10000u128));
+        assert_eq!(fury_balance(&suite, club_staking.as_str()), Uint128::from(9000u128));
+        let stakes: Vec<club_staking::state::ClubStakingDetails> = suite.app.wrap().query_wasm_smart(
+            club_staking.clone(),
+            &club_staking::msg::QueryMsg::AllStakesForUser { user_address: GAMER_1.to_string() },
+        ).unwrap();
+        assert_eq!(stakes.len(), 1);
+        assert_eq!(stakes[0].club_name, "CLUB001".to_string());
+        assert_eq!(stakes[0].staked_amount, Uint128::from(9000u128));
+    }
+
+    #[test]
+    fn test_swap_failure_retry_and_minimum_received() {
+        let mut suite = setup_suite();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        let swap_msg = |amount: u128, min_fury_received: Option<u128>| ExecuteMsg::Swap {
+            amount: Uint128::from(amount),
+            game_id: "Game001".to_string(),
+            pool_id: "1".to_string(),
+            max_spread: None,
+            min_fury_received: min_fury_received.map(Uint128::from),
+        };
+
+        // The contract only holds 2070 UST, the proxy never gets the funds
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &swap_msg(3000, None),
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm").add_attribute("swap_status", "failed"));
+        let ledger: PoolLedger = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::PoolLedger {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+        ).unwrap();
+        assert_eq!(ledger.swapped, Uint128::zero());
+
+        // 1800 UST buys 18000 FURY, asking for more reverts the swap
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &swap_msg(1800, Some(18001)),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Swap returned less FURY than the minimum"),
+        }));
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &swap_msg(1800, Some(18000)),
+            &[],
+        ).unwrap();
+        rsp.assert_event(&Event::new("wasm")
+            .add_attribute("swap_status", "succeeded")
+            .add_attribute("fury_balance_gained", "18000"));
+        let history: Vec<SwapAttempt> = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::SwapHistory {
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+            },
+        ).unwrap();
+        // The reverted swap left no trace, not even its reply id
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].status, SwapStatus::Failed);
+        assert!(history[0].error.is_some());
+        assert_eq!(history[1].status, SwapStatus::Succeeded);
+        assert_eq!(history[1].reply_id, 2u64);
+        assert_eq!(history[1].fury_received, Uint128::from(18000u128));
+        let swap_info: SwapBalanceDetails = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::SwapInfo { pool_id: "1".to_string() },
+        ).unwrap();
+        assert_eq!(swap_info.ust_amount_swapped, Uint128::from(1800u128));
+        assert_eq!(swap_info.ust_for_rake, Uint128::from(200u128));
+
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &swap_msg(100, None),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool collection is already swapped"),
+        }));
+    }
+
+    #[test]
+    fn test_no_swap_for_pools_owed_refunds() {
+        let mut suite = setup_suite();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CreatePool {
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                invitees: None,
+                join_code_hash: None,
+            },
+            &[],
+        ).unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+        approve(&mut suite, GAMER_2).unwrap();
+        submit_bid(&mut suite, GAMER_2, "Team002").unwrap();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid_in_pool(&mut suite, GAMER_1, "2", "Team001", None).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::LockGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        let swap_msg = |pool_id: &str| ExecuteMsg::Swap {
+            amount: Uint128::from(900u128),
+            game_id: "Game001".to_string(),
+            pool_id: pool_id.to_string(),
+            max_spread: None,
+            min_fury_received: None,
+        };
+
+        // Pool 2 is below the minimum and its UST is owed back as refunds
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &swap_msg("2"),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool is flagged for refund"),
+        }));
+
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CancelGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &swap_msg("1"),
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::Std(StdError::GenericErr {
+            msg: String::from("Pool cant be swapped as game is cancelled"),
+        }));
+        assert_eq!(fury_balance(&suite, suite.gaming_pool.as_str()), Uint128::zero());
+    }
+
+    #[test]
+    fn test_cw20_receive_bid_and_whitelist_restriction() {
+        let mut suite = setup_suite();
+        let gaming_pool = suite.gaming_pool.to_string();
+        // The contract pays the proxy swap fee itself on cw20 bids
+        suite.app.init_bank_balance(&suite.gaming_pool, vec![coin(100, "uusd")]).unwrap();
+
+        // The 40 UST fee is taken as 400 FURY on top of the pool fee
+        let bid_msg = ReceivedMsg::GamePoolBidSubmit(GamePoolBidSubmitCommand {
+            gamer: GAMER_1.to_string(),
+            game_id: "Game001".to_string(),
+            pool_type: "oneToTwo".to_string(),
+            pool_id: "1".to_string(),
+            team_id: "Team001".to_string(),
+            max_spread: None,
+            join_code: None,
+        });
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.fury.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: gaming_pool.clone(),
+                amount: Uint128::from(10400u128),
+                msg: to_binary(&bid_msg).unwrap(),
+            },
+            &[],
+        ).unwrap();
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(9600u128));
+        assert_eq!(fury_balance(&suite, FEE_WALLET), Uint128::from(400u128));
+        assert_eq!(ust_balance(&suite, &gaming_pool), Uint128::from(1095u128));
+
+        // Wallets restricted on the FURY token cannot bid, even with
+        // an allowance given before the restriction
+        approve(&mut suite, GAMER_2).unwrap();
+        let restricted_until = suite.app.block_info().time.plus_seconds(1000);
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.fury.clone(),
+            &Cw20ExecuteMsg::SetWhiteListExpirationTimestamp { timestamp: restricted_until },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.fury.clone(),
+            &Cw20ExecuteMsg::RestrictedWalletListUpdate {
+                add_list: vec![GAMER_2.to_string()],
+                remove_list: vec![],
+            },
+            &[],
+        ).unwrap();
+        let err = submit_bid(&mut suite, GAMER_2, "Team002").unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::UserIsRestricted {});
+    }
+
+    #[test]
+    fn test_paused_contract_blocks_bids_but_not_refunds() {
+        let mut suite = setup_suite();
+        approve(&mut suite, GAMER_1).unwrap();
+        submit_bid(&mut suite, GAMER_1, "Team001").unwrap();
+
+        // Only the admin can pause
+        let err = suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetActivationStatus { is_active: false },
+            &[],
+        ).unwrap_err();
+        assert_eq!(
+            err.downcast::<ContractError>().unwrap(),
+            ContractError::Unauthorized { invoker: GAMER_1.to_string() }
+        );
+        let rsp = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::SetActivationStatus { is_active: false },
+            &[],
+        ).unwrap();
+        assert!(rsp.events.iter().any(|event| event.attributes.iter().any(|attr| {
+            attr.key == "action" && attr.value == "set_activation_status"
+        })));
+        let is_active: bool = suite.app.wrap().query_wasm_smart(
+            suite.gaming_pool.clone(),
+            &QueryMsg::ActivationStatus {},
+        ).unwrap();
+        assert_eq!(is_active, false);
+
+        approve(&mut suite, GAMER_2).unwrap();
+        let err = submit_bid(&mut suite, GAMER_2, "Team002").unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::ContractPaused {});
+        let err = suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::Swap {
+                amount: Uint128::from(900u128),
+                game_id: "Game001".to_string(),
+                pool_id: "1".to_string(),
+                max_spread: None,
+                min_fury_received: None,
+            },
+            &[],
+        ).unwrap_err();
+        assert_eq!(err.downcast::<ContractError>().unwrap(), ContractError::ContractPaused {});
+
+        // Cancelled games can still be refunded while paused, the 1000 UST
+        // pool fee comes back as 10000 FURY
+        suite.app.init_bank_balance(&suite.gaming_pool, vec![coin(2000, "uusd")]).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(ADMIN),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::CancelGame { game_id: "Game001".to_string() },
+            &[],
+        ).unwrap();
+        suite.app.execute_contract(
+            Addr::unchecked(GAMER_1),
+            suite.gaming_pool.clone(),
+            &ExecuteMsg::ClaimRefund {
+                gamer: GAMER_1.to_string(),
+                max_spread: None,
+                pool_ids: None,
+                limit: None,
+            },
+            &[],
+        ).unwrap();
+        assert_eq!(fury_balance(&suite, GAMER_1), Uint128::from(20000u128));
+    }
+}
@@ -0,0 +1,63 @@
+use astroport::asset::Asset;
+use astroport::pair::ExecuteMsg as AstroPortExecute;
+use cosmwasm_std::{QuerierWrapper, StdResult, to_binary, Uint128};
+
+use crate::msg::{ProxyQueryMsgs, QueryMsgSimulation};
+
+/// Source of the FURY/UST prices and the swap and tax fees the contract
+/// needs. Execute paths take this instead of querying the proxy directly,
+/// so tests can run them unmodified against deterministic prices.
+pub trait PriceOracle {
+    /// FURY amount worth the given UST amount
+    fn fury_equivalent_to_ust(&self, ust_count: Uint128) -> StdResult<Uint128>;
+    /// UST amount worth the given FURY amount
+    fn ust_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128>;
+    /// UST the proxy charges on top of the swap
+    fn swap_fee(&self, swap_message: &AstroPortExecute) -> StdResult<Uint128>;
+    /// Chain tax on sending the asset
+    fn tax(&self, asset: &Asset) -> StdResult<Uint128>;
+}
+
+/// Prices and fees from the astroport proxy contract, used on chain
+pub struct ProxyOracle<'a> {
+    querier: QuerierWrapper<'a>,
+    proxy_address: String,
+}
+
+impl<'a> ProxyOracle<'a> {
+    pub fn new(querier: QuerierWrapper<'a>, proxy_address: String) -> Self {
+        ProxyOracle {
+            querier,
+            proxy_address,
+        }
+    }
+}
+
+impl<'a> PriceOracle for ProxyOracle<'a> {
+    fn fury_equivalent_to_ust(&self, ust_count: Uint128) -> StdResult<Uint128> {
+        self.querier.query_wasm_smart(
+            self.proxy_address.clone(),
+            &ProxyQueryMsgs::get_fury_equivalent_to_ust { ust_count },
+        )
+    }
+
+    fn ust_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128> {
+        self.querier.query_wasm_smart(
+            self.proxy_address.clone(),
+            &ProxyQueryMsgs::get_ust_equivalent_to_fury { fury_count },
+        )
+    }
+
+    fn swap_fee(&self, swap_message: &AstroPortExecute) -> StdResult<Uint128> {
+        self.querier.query_wasm_smart(
+            self.proxy_address.clone(),
+            &QueryMsgSimulation::QueryPlatformFees {
+                msg: to_binary(swap_message)?,
+            },
+        )
+    }
+
+    fn tax(&self, asset: &Asset) -> StdResult<Uint128> {
+        asset.compute_tax(&self.querier)
+    }
+}
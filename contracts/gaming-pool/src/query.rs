@@ -1,9 +1,39 @@
-use cosmwasm_std::{Deps, Order, StdError, StdResult, Storage, Uint128};
+use cosmwasm_std::{BlockInfo, Deps, Env, Order, StdError, StdResult, Storage, Uint128};
+use cw0::{calc_range_start_string, Duration};
+use cw20::{BalanceResponse, Cw20QueryMsg, Denom};
+use cw_storage_plus::Bound;
 
-use crate::contract::{DUMMY_WALLET, INITIAL_TEAM_POINTS, INITIAL_TEAM_RANK,
+use crate::contract::{DEFAULT_QUERY_LIMIT, DUMMY_WALLET, MAX_QUERY_LIMIT, GAME_POOL_CLOSED, GAME_POOL_OPEN, INITIAL_TEAM_POINTS, INITIAL_TEAM_RANK,
                       UNCLAIMED_REFUND, UNCLAIMED_REWARD};
-use crate::execute::query_platform_fees;
-use crate::state::{CONFIG, FeeDetails, GAME_DETAILS, GAME_RESULT_DUMMY, GameDetails, GameResult, POOL_DETAILS, POOL_TEAM_DETAILS, POOL_TYPE_DETAILS, PoolDetails, PoolTeamDetails, PoolTypeDetails, SWAP_BALANCE_INFO, SwapBalanceDetails};
+use crate::execute::{add_asset_amount, query_platform_fees};
+use crate::msg::{AssetSolvency, GamerAssetPnl, GamerStatsResponse, LeaderboardEntry, RolesResponse, SolvencyReport};
+use crate::state::{ACTIVATION_STATUS, ADMIN, CLAIM_SETTINGS, ClaimSettings, CONFIG, GAME_OPERATOR, PENDING_ADMIN, TREASURY, FeeDetails, GAME_DETAILS, GAMER_STATS, GAME_RESULT_DUMMY, GameDetails, GameResult, pools, pool_teams, POOL_TYPE_DETAILS, PENDING_POOL_RESULTS, PendingPoolResults, POOL_ACCESS, POOL_LEADERBOARD, POOL_TEMPLATES, PoolAccess, PoolTemplate, PoolDetails, POOL_LEDGERS, POOL_OBLIGATIONS, PoolLedger, PoolTeamDetails, PoolTypeDetails, POOL_PARAMS,
+                   RESULT_DISPUTE_CONFIG, ResultDisputeConfig, SWAP_BALANCE_INFO, SWAP_HISTORY, SwapAttempt, SwapBalanceDetails,
+                   USER_POOLS, UserPoolDetails};
+
+pub fn query_roles(deps: Deps) -> StdResult<RolesResponse> {
+    let pending_admin = PENDING_ADMIN.may_load(deps.storage)?;
+    return Ok(RolesResponse {
+        admin: ADMIN.query_admin(deps)?.admin,
+        pending_admin: pending_admin.map(String::from),
+        game_operator: GAME_OPERATOR.query_admin(deps)?.admin,
+        treasury: TREASURY.query_admin(deps)?.admin,
+    });
+}
+
+// Contracts instantiated before the switch was enforced have no status saved
+// and are active
+pub fn query_activation_status(storage: &dyn Storage) -> StdResult<bool> {
+    let activation_status = ACTIVATION_STATUS.may_load(storage)?;
+    match activation_status {
+        Some(is_active) => {
+            return Ok(is_active);
+        }
+        None => {
+            return Ok(true);
+        }
+    }
+}
 
 pub fn query_pool_type_details(
     storage: &dyn Storage,
@@ -25,72 +55,68 @@ pub fn query_total_fees(
     return Ok(result);
 }
 
-pub fn query_all_pool_type_details(storage: &dyn Storage) -> StdResult<Vec<PoolTypeDetails>> {
-    let mut all_pool_types = Vec::new();
-    let all_pool_type_names: Vec<String> = POOL_TYPE_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
+pub fn query_all_pool_type_details(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PoolTypeDetails>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::exclusive);
+    let all_pool_types: StdResult<Vec<PoolTypeDetails>> = POOL_TYPE_DETAILS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, pool_type)| pool_type))
         .collect();
-    for ptn in all_pool_type_names {
-        let pool_type = POOL_TYPE_DETAILS.load(storage, ptn)?;
-        all_pool_types.push(pool_type);
-    }
-    return Ok(all_pool_types);
+    return all_pool_types;
 }
 
 pub fn query_pool_team_details(
     storage: &dyn Storage,
+    game_id: String,
     pool_id: String,
     user: String,
 ) -> StdResult<Vec<PoolTeamDetails>> {
-    let ptd = POOL_TEAM_DETAILS.may_load(storage, (&*pool_id, user.as_ref()))?;
+    let ptd = pool_teams().may_load(storage, (&*game_id, &*pool_id, user.as_ref()))?;
     match ptd {
         Some(ptd) => return Ok(ptd),
         None => return Err(StdError::generic_err("No team details found")),
     };
 }
 
-pub fn query_all_teams(storage: &dyn Storage, users: Vec<String>) -> StdResult<Vec<PoolTeamDetails>> {
+pub fn query_all_teams(storage: &dyn Storage, game_id: String, users: Vec<String>) -> StdResult<Vec<PoolTeamDetails>> {
     let mut all_teams = Vec::new();
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        for user in users.clone() {
-            let team_details = POOL_TEAM_DETAILS.load(storage, (&*pool_id.clone(), user.as_ref()));
-            match team_details {
-                Ok(teams) => {
-                    for team in teams {
-                        all_teams.push(team);
-                    }
-                }
-                Err(_) => {
-                    //     pass
-                }
+    for user in users {
+        let user_pools: Vec<Vec<PoolTeamDetails>> = pool_teams()
+            .idx
+            .gamer
+            .prefix((user.as_bytes().to_vec(), game_id.as_bytes().to_vec()))
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, teams)| teams))
+            .collect::<StdResult<Vec<Vec<PoolTeamDetails>>>>()?;
+        for teams in user_pools {
+            for team in teams {
+                all_teams.push(team);
             }
         }
     }
     return Ok(all_teams);
 }
 
+// Team entries of every pool the gamer has entered, across all games
+pub fn get_pool_teams_for_gamer(storage: &dyn Storage, gamer: String) -> StdResult<Vec<Vec<PoolTeamDetails>>> {
+    let gamer_pools: StdResult<Vec<Vec<PoolTeamDetails>>> = pool_teams()
+        .idx
+        .gamer
+        .sub_prefix(gamer.as_bytes().to_vec())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, teams)| teams))
+        .collect();
+    return gamer_pools;
+}
+
 pub fn query_reward(storage: &dyn Storage, gamer: String) -> StdResult<Uint128> {
     let mut user_reward = Uint128::zero();
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        // Get the existing teams for this pool
-        let mut teams = Vec::new();
-        let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
-        match all_teams {
-            Some(some_teams) => {
-                teams = some_teams;
-            }
-            None => {}
-        }
+    for teams in get_pool_teams_for_gamer(storage, gamer.clone())? {
         for team in teams {
             if gamer == team.gamer_address && team.claimed_reward == UNCLAIMED_REWARD {
                 user_reward += team.reward_amount;
@@ -102,21 +128,7 @@ pub fn query_reward(storage: &dyn Storage, gamer: String) -> StdResult<Uint128>
 
 pub fn query_refund(storage: &dyn Storage, gamer: String) -> StdResult<Uint128> {
     let mut user_reward = Uint128::zero();
-    // Get all pools
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        // Get the existing teams for this pool
-        let mut teams = Vec::new();
-        let all_teams = POOL_TEAM_DETAILS.may_load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
-        match all_teams {
-            Some(some_teams) => {
-                teams = some_teams;
-            }
-            None => {}
-        }
+    for teams in get_pool_teams_for_gamer(storage, gamer.clone())? {
         for team in teams {
             if gamer == team.gamer_address && team.claimed_refund == UNCLAIMED_REFUND {
                 user_reward += team.refund_amount;
@@ -128,13 +140,11 @@ pub fn query_refund(storage: &dyn Storage, gamer: String) -> StdResult<Uint128>
 
 pub fn query_game_result(
     deps: Deps,
+    game_id: String,
     gamer: String,
     pool_id: String,
     team_id: String,
 ) -> StdResult<GameResult> {
-    let config = CONFIG.load(deps.storage)?;
-    let game_id = config.game_id;
-
     let mut reward_amount = Uint128::zero();
     let mut refund_amount = Uint128::zero();
     let mut team_rank = INITIAL_TEAM_RANK;
@@ -153,7 +163,7 @@ pub fn query_game_result(
 
     // Get the existing teams for this pool
     let mut teams = Vec::new();
-    let all_teams = POOL_TEAM_DETAILS.may_load(deps.storage, (&*pool_id.clone(), gamer.as_ref()))?;
+    let all_teams = pool_teams().may_load(deps.storage, (&*game_id, &*pool_id.clone(), gamer.as_ref()))?;
     match all_teams {
         Some(some_teams) => {
             teams = some_teams;
@@ -186,8 +196,8 @@ pub fn query_game_result(
     return Ok(game_result);
 }
 
-pub fn query_pool_details(storage: &dyn Storage, pool_id: String) -> StdResult<PoolDetails> {
-    let pd = POOL_DETAILS.may_load(storage, pool_id.clone())?;
+pub fn query_pool_details(storage: &dyn Storage, game_id: String, pool_id: String) -> StdResult<PoolDetails> {
+    let pd = pools().may_load(storage, (&*game_id, &*pool_id))?;
     match pd {
         Some(pd) => return Ok(pd),
         None => return Err(StdError::generic_err("No pool details found")),
@@ -201,14 +211,16 @@ pub fn get_team_count_for_user_in_pool_type(
     pool_type: String,
 ) -> StdResult<u32> {
     let mut count = 0;
-    let all_pools: Vec<String> = POOL_TEAM_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
-        .collect();
-    for pool_id in all_pools {
-        let team_details = POOL_TEAM_DETAILS.load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
+    let user_pools: Vec<Vec<PoolTeamDetails>> = pool_teams()
+        .idx
+        .gamer
+        .prefix((gamer.as_bytes().to_vec(), game_id.as_bytes().to_vec()))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, teams)| teams))
+        .collect::<StdResult<Vec<Vec<PoolTeamDetails>>>>()?;
+    for team_details in user_pools {
         for team in team_details {
-            if team.pool_type == pool_type && team.game_id == game_id && team.gamer_address == gamer && team.pool_id == pool_id
+            if team.pool_type == pool_type && team.game_id == game_id && team.gamer_address == gamer
             {
                 count += 1;
             }
@@ -218,24 +230,53 @@ pub fn get_team_count_for_user_in_pool_type(
     return Ok(count);
 }
 
-pub fn query_game_details(storage: &dyn Storage) -> StdResult<GameDetails> {
-    let config = CONFIG.load(storage)?;
-    let game_id = config.game_id;
+// An open game counts as closed once its entry close time has passed,
+// even if the admin has not locked it yet
+pub fn get_game_status(game: &GameDetails, block: &BlockInfo) -> u64 {
+    if game.game_status == GAME_POOL_OPEN && game.entry_close_time.is_expired(block) {
+        return GAME_POOL_CLOSED;
+    }
+    return game.game_status;
+}
 
+pub fn query_game_details(storage: &dyn Storage, block: &BlockInfo, game_id: String) -> StdResult<GameDetails> {
     let game_detail = GAME_DETAILS.may_load(storage, game_id)?;
     match game_detail {
-        Some(game_detail) => return Ok(game_detail),
+        Some(mut game_detail) => {
+            game_detail.game_status = get_game_status(&game_detail, block);
+            return Ok(game_detail);
+        }
         None => return Err(StdError::generic_err("No Game detail found")),
     };
 }
 
+pub fn query_all_games(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<GameDetails>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::exclusive);
+    let all_games: StdResult<Vec<GameDetails>> = GAME_DETAILS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, mut game)| {
+            game.game_status = get_game_status(&game, block);
+            game
+        }))
+        .collect();
+    return all_games;
+}
+
 pub fn query_team_details(
     storage: &dyn Storage,
+    game_id: String,
     pool_id: String,
     team_id: String,
     gamer: String,
 ) -> StdResult<PoolTeamDetails> {
-    let team_details = POOL_TEAM_DETAILS.load(storage, (&*pool_id.clone(), gamer.as_ref()))?;
+    let team_details = pool_teams().load(storage, (&*game_id, &*pool_id.clone(), gamer.as_ref()))?;
     for team in team_details {
         if team.team_id == team_id.to_string() {
             return Ok(team.clone());
@@ -244,33 +285,32 @@ pub fn query_team_details(
     return Err(StdError::generic_err("Pool Team Details not found"));
 }
 
-pub fn query_all_pools_in_game(storage: &dyn Storage) -> StdResult<Vec<PoolDetails>> {
-    let config = CONFIG.load(storage)?;
-    let game_id = config.game_id;
-
-    let mut all_pool_details = Vec::new();
-    let all_pools: Vec<String> = POOL_DETAILS
-        .keys(storage, None, None, Order::Ascending)
-        .map(|k| String::from_utf8(k).unwrap())
+pub fn query_all_pools_in_game(
+    storage: &dyn Storage,
+    game_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PoolDetails>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = calc_range_start_string(start_after).map(Bound::exclusive);
+    let all_pool_details: StdResult<Vec<PoolDetails>> = pools()
+        .prefix(&game_id)
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, pool)| pool))
         .collect();
-    for pool_name in all_pools {
-        let pool_details = POOL_DETAILS.load(storage, pool_name)?;
-        if pool_details.game_id == game_id {
-            all_pool_details.push(pool_details);
-        }
-    }
-    return Ok(all_pool_details);
+    return all_pool_details;
 }
 
-pub fn query_pool_collection(storage: &dyn Storage, pool_id: String) -> StdResult<Uint128> {
-    let pd = POOL_DETAILS.may_load(storage, pool_id.clone())?;
+pub fn query_pool_collection(storage: &dyn Storage, game_id: String, pool_id: String) -> StdResult<Uint128> {
+    let pd = pools().may_load(storage, (&*game_id, &*pool_id))?;
     let pool;
     match pd {
         Some(pd) => pool = pd,
         None => return Err(StdError::generic_err("No pool details found")),
     };
 
-    let ptd = POOL_TYPE_DETAILS.may_load(storage, pool.pool_type.clone())?;
+    let ptd = may_load_pool_params(storage, &game_id, &pool_id)?;
     let pool_type;
     match ptd {
         Some(ptd) => {
@@ -293,3 +333,264 @@ pub fn query_swap_data_for_pool(
     let info = SWAP_BALANCE_INFO.load(storage, pool_id)?;
     return Ok(info)
 }
+
+pub fn query_pool_ledger(storage: &dyn Storage, game_id: String, pool_id: String) -> StdResult<PoolLedger> {
+    let ledger = POOL_LEDGERS.may_load(storage, (&*game_id, &*pool_id))?;
+    return Ok(ledger.unwrap_or_default());
+}
+
+// What a pool still owes, per asset. Pools paid in FURY owe the UST not yet
+// swapped or paid out as rake or refunds, and the FURY bought for their prize
+// pool until it is claimed. Once the final batch is distributed only the
+// rewards assigned and not claimed are owed
+pub fn pool_obligations(storage: &dyn Storage, pool: &PoolDetails) -> StdResult<Vec<(Denom, Uint128)>> {
+    let mut obligations: Vec<(Denom, Uint128)> = Vec::new();
+    let ledger = POOL_LEDGERS
+        .may_load(storage, (&pool.game_id, &pool.pool_id))?
+        .unwrap_or_default();
+    let pool_type = may_load_pool_params(storage, &pool.game_id, &pool.pool_id)?;
+    let entry_asset;
+    match pool_type {
+        Some(pool_type) => entry_asset = pool_type.entry_asset,
+        None => return Err(StdError::generic_err("No pool type details found")),
+    }
+    match entry_asset {
+        None => {
+            let config = CONFIG.load(storage)?;
+            let mut fury_owed = ledger.rewards_assigned;
+            if !pool.rewards_distributed {
+                let swap_info = SWAP_BALANCE_INFO.may_load(storage, pool.pool_id.clone())?;
+                match swap_info {
+                    Some(swap_info) => {
+                        let bought = swap_info.balance_post_swap
+                            .checked_sub(swap_info.balance_pre_swap)
+                            .unwrap_or_default();
+                        if bought > fury_owed {
+                            fury_owed = bought;
+                        }
+                    }
+                    None => {}
+                }
+                let ust_owed = ledger.collected
+                    .checked_sub(ledger.swapped + ledger.rake_paid + ledger.refunds_claimed)
+                    .unwrap_or_default();
+                add_asset_amount(&mut obligations, Denom::Native("uusd".to_string()), ust_owed);
+            }
+            let fury_owed = fury_owed.checked_sub(ledger.rewards_claimed).unwrap_or_default();
+            add_asset_amount(&mut obligations, Denom::Cw20(config.minting_contract_address), fury_owed);
+        }
+        Some(asset) => {
+            let owed;
+            if pool.rewards_distributed {
+                owed = ledger.rewards_assigned.checked_sub(ledger.rewards_claimed).unwrap_or_default();
+            } else {
+                owed = ledger.collected
+                    .checked_sub(ledger.rake_paid + ledger.refunds_claimed + ledger.rewards_claimed)
+                    .unwrap_or_default();
+            }
+            add_asset_amount(&mut obligations, asset, owed);
+        }
+    }
+    return Ok(obligations);
+}
+
+// What all the pools still owe, per asset, from the running totals
+pub fn query_pool_obligations(storage: &dyn Storage) -> StdResult<Vec<(Denom, Uint128)>> {
+    let config = CONFIG.load(storage)?;
+    let mut obligations: Vec<(Denom, Uint128)> = vec![
+        (Denom::Cw20(config.minting_contract_address), Uint128::zero()),
+        (Denom::Native("uusd".to_string()), Uint128::zero()),
+    ];
+    let totals = POOL_OBLIGATIONS.may_load(storage)?.unwrap_or_default();
+    for (asset, owed) in totals {
+        add_asset_amount(&mut obligations, asset, owed);
+    }
+    return Ok(obligations);
+}
+
+pub fn query_solvency_report(deps: Deps, env: Env) -> StdResult<SolvencyReport> {
+    let obligations = query_pool_obligations(deps.storage)?;
+    let mut assets = Vec::new();
+    let mut is_solvent = true;
+    for (asset, owed) in obligations {
+        let balance;
+        match asset.clone() {
+            Denom::Native(denom) => {
+                balance = deps.querier.query_balance(env.contract.address.clone(), denom)?.amount;
+            }
+            Denom::Cw20(token) => {
+                let response: BalanceResponse = deps.querier.query_wasm_smart(
+                    token,
+                    &Cw20QueryMsg::Balance {
+                        address: env.contract.address.to_string(),
+                    },
+                )?;
+                balance = response.balance;
+            }
+        }
+        if balance < owed {
+            is_solvent = false;
+        }
+        assets.push(AssetSolvency {
+            asset,
+            balance,
+            obligations: owed,
+        });
+    }
+    return Ok(SolvencyReport {
+        assets,
+        is_solvent,
+    });
+}
+
+pub fn query_pool_template(storage: &dyn Storage, pool_type: String) -> StdResult<Option<PoolTemplate>> {
+    return POOL_TEMPLATES.may_load(storage, pool_type);
+}
+
+pub fn query_user_pool(storage: &dyn Storage, game_id: String, pool_id: String) -> StdResult<Option<UserPoolDetails>> {
+    return USER_POOLS.may_load(storage, (&*game_id, &*pool_id));
+}
+
+// Parameters a pool was created with. Pools created before the parameters were
+// kept per pool follow the current parameters of their pool type
+pub fn may_load_pool_params(storage: &dyn Storage, game_id: &str, pool_id: &str) -> StdResult<Option<PoolTypeDetails>> {
+    let params = POOL_PARAMS.may_load(storage, (game_id, pool_id))?;
+    match params {
+        Some(params) => return Ok(Some(params)),
+        None => {}
+    }
+    let pool = pools().may_load(storage, (game_id, pool_id))?;
+    match pool {
+        Some(pool) => return POOL_TYPE_DETAILS.may_load(storage, pool.pool_type),
+        None => return Ok(None),
+    }
+}
+
+pub fn query_pool_params(storage: &dyn Storage, game_id: &str, pool_id: &str) -> StdResult<PoolTypeDetails> {
+    let params = may_load_pool_params(storage, game_id, pool_id)?;
+    match params {
+        Some(params) => return Ok(params),
+        None => return Err(StdError::generic_err("No pool type details found")),
+    }
+}
+
+// Pools created by players hold the number of teams their creator chose
+pub fn query_max_teams_for_pool(
+    storage: &dyn Storage,
+    game_id: &str,
+    pool_id: &str,
+    pool_type: &PoolTypeDetails,
+) -> StdResult<u32> {
+    match USER_POOLS.may_load(storage, (game_id, pool_id))? {
+        Some(user_pool) => return Ok(user_pool.max_teams_for_pool),
+        None => return Ok(pool_type.max_teams_for_pool),
+    }
+}
+
+pub fn query_pool_leaderboard(
+    storage: &dyn Storage,
+    game_id: String,
+    pool_id: String,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> StdResult<Vec<LeaderboardEntry>> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.unwrap_or_default() as usize;
+    // Ranked teams are read in order from the leaderboard, so only the teams
+    // up to the end of the page are loaded
+    let ranked = POOL_LEADERBOARD
+        .prefix((&*game_id, &*pool_id))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| -> StdResult<Vec<StdResult<PoolTeamDetails>>> {
+            let (gamer, team_id) = item?.1;
+            let teams = pool_teams().load(storage, (&*game_id, &*pool_id, &*gamer))?;
+            return Ok(teams.into_iter().filter(|t| t.team_id == team_id).map(Ok).collect());
+        });
+    // Unranked teams keep the initial rank and come last
+    let unranked = pool_teams()
+        .prefix((&*game_id, &*pool_id))
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| -> StdResult<Vec<StdResult<PoolTeamDetails>>> {
+            let teams = item?.1;
+            return Ok(teams.into_iter().filter(|t| t.team_rank == INITIAL_TEAM_RANK).map(Ok).collect());
+        });
+    let teams = ranked
+        .chain(unranked)
+        .flat_map(|teams| teams.unwrap_or_else(|err| vec![Err(err)]));
+    let mut leaderboard = Vec::new();
+    for (index, team) in teams.enumerate().skip(start).take(limit) {
+        let team = team?;
+        leaderboard.push(LeaderboardEntry {
+            position: index as u32 + 1,
+            gamer_address: team.gamer_address,
+            team_id: team.team_id,
+            team_rank: team.team_rank,
+            team_points: team.team_points,
+            reward_amount: team.reward_amount,
+        });
+    }
+    return Ok(leaderboard);
+}
+
+pub fn query_gamer_stats(storage: &dyn Storage, gamer: String) -> StdResult<GamerStatsResponse> {
+    let stats = GAMER_STATS.may_load(storage, &gamer)?.unwrap_or_default();
+    let mut assets = Vec::new();
+    for asset_stats in stats.assets {
+        let returned = asset_stats.won + asset_stats.refunded;
+        assets.push(GamerAssetPnl {
+            asset: asset_stats.asset,
+            spent: asset_stats.spent,
+            won: asset_stats.won,
+            refunded: asset_stats.refunded,
+            net_profit: returned.checked_sub(asset_stats.spent).unwrap_or_default(),
+            net_loss: asset_stats.spent.checked_sub(returned).unwrap_or_default(),
+        });
+    }
+    return Ok(GamerStatsResponse {
+        pools_entered: stats.pools_entered,
+        teams_entered: stats.teams_entered,
+        wins: stats.wins,
+        assets,
+    });
+}
+
+pub fn query_pool_access(storage: &dyn Storage, game_id: String, pool_id: String) -> StdResult<Option<PoolAccess>> {
+    return POOL_ACCESS.may_load(storage, (&*game_id, &*pool_id));
+}
+
+pub fn query_swap_history(storage: &dyn Storage, game_id: String, pool_id: String) -> StdResult<Vec<SwapAttempt>> {
+    return Ok(SWAP_HISTORY.may_load(storage, (&*game_id, &*pool_id))?.unwrap_or_default());
+}
+
+pub fn query_claim_settings(storage: &dyn Storage, gamer: String) -> StdResult<ClaimSettings> {
+    return Ok(CLAIM_SETTINGS.may_load(storage, &gamer)?.unwrap_or_default());
+}
+
+pub fn query_pending_game_results(
+    storage: &dyn Storage,
+    game_id: String,
+    pool_id: String,
+) -> StdResult<PendingPoolResults> {
+    let pr = PENDING_POOL_RESULTS.may_load(storage, (&*game_id, &*pool_id))?;
+    match pr {
+        Some(pr) => return Ok(pr),
+        None => return Err(StdError::generic_err("No pending results found for pool")),
+    };
+}
+
+// Until the admin sets dispute params results can be finalised right away
+pub fn query_result_dispute_config(
+    storage: &dyn Storage,
+) -> StdResult<ResultDisputeConfig> {
+    let rdc = RESULT_DISPUTE_CONFIG.may_load(storage)?;
+    match rdc {
+        Some(rdc) => return Ok(rdc),
+        None => {
+            return Ok(ResultDisputeConfig {
+                challenge_period: Duration::Time(0),
+                oracles: Vec::new(),
+                veto_threshold: 1u32,
+            });
+        }
+    };
+}
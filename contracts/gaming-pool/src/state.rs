@@ -1,25 +1,37 @@
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cw20::AllowanceResponse;
-use cw_storage_plus::{Item, Map};
+use cw0::{Duration, Expiration};
+use cw20::{AllowanceResponse, Denom};
+use cw3::Vote;
+use cw_controllers::Admin;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, U64Key};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct Config {
-    pub admin_address: Addr,
     pub minting_contract_address: Addr,
     pub platform_fees_collector_wallet: Addr,
     pub astro_proxy_address: Addr,
     pub platform_fee: Uint128,
     pub transaction_fee: Uint128,
-    pub game_id: String,
 }
 
 pub const CONFIG_KEY: &str = "config";
 pub const CONFIG: Item<Config> = Item::new(CONFIG_KEY);
+
+// Super-admin, manages the other roles and the pool type and dispute params
+pub const ADMIN: Admin = Admin::new("admin");
+// Admin proposed by the current one, takes over once it accepts
+pub const PENDING_ADMIN: Item<Addr> = Item::new("pending_admin");
+// Runs games: creates games and pools, locks, swaps and distributes
+pub const GAME_OPERATOR: Admin = Admin::new("game_operator");
+// Sweeps the contract balance and sets the platform fee wallets
+pub const TREASURY: Admin = Admin::new("treasury");
 // This param will enable or disable contract completely as a fail safe
 pub const ACTIVATION_STATUS: Item<bool> = Item::new("activation_status");
+// Club staking contract FURY rewards can be staked into when claimed
+pub const CLUB_STAKING_CONTRACT: Item<Addr> = Item::new("club_staking_contract");
 
 /// This is used for saving various vesting details
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
@@ -30,6 +42,10 @@ pub struct GameDetails {
 
     /// Current status of the game - open, close, canceled
     pub game_status: u64,
+
+    /// Bids are rejected once this expires, the game is then
+    /// treated as closed without waiting for the admin to lock it
+    pub entry_close_time: Expiration,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
@@ -73,6 +89,40 @@ pub struct PoolTypeDetails {
 
     /// Rake distribution 
     pub rake_list: Vec<WalletPercentage>,
+
+    /// Share of the prize pool paid out by rank
+    #[serde(default)]
+    pub payout_tiers: Vec<PayoutTier>,
+
+    /// Asset the pool fee is paid in. Without one the entry is taken in FURY
+    /// and swapped through the proxy, otherwise the asset is kept as it is
+    /// and rewards and refunds are paid out in it
+    #[serde(default)]
+    pub entry_asset: Option<Denom>,
+
+    /// Kept out of the pool fee refunded to a team withdrawn before the
+    /// game is locked. Taken in the same asset as the pool fee
+    #[serde(default)]
+    pub withdrawal_fee: Uint128,
+
+    /// No new pools of a disabled type can be created, existing ones carry on
+    #[serde(default)]
+    pub is_disabled: bool,
+}
+
+/// Share of the prize pool paid to every rank from `from_rank` to `to_rank`.
+/// Teams tied at a rank split the shares of all the ranks they occupy equally.
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PayoutTier {
+    /// First rank of the tier, 1 is the winner
+    pub from_rank: u64,
+
+    /// Last rank of the tier (inclusive)
+    pub to_rank: u64,
+
+    /// Share for each rank in the tier, 10000 is the whole prize pool
+    pub percentage: u32,
 }
 
 /// This is used for saving various vesting details
@@ -137,6 +187,18 @@ pub struct PoolTeamDetails {
 
     /// team rank in the pool updated after each game
     pub team_rank: u64,
+
+    /// Team ids the team was entered with before, oldest first
+    #[serde(default)]
+    pub team_changes: Vec<TeamChange>,
+}
+
+/// A team id replaced while the game was still open
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct TeamChange {
+    pub previous_team_id: String,
+    pub changed_at: Timestamp,
 }
 
 /// This is used for saving game result details
@@ -154,6 +216,27 @@ pub struct GameResult {
     pub team_points: u64,
 }
 
+/// Rank and points of a team submitted by the admin once the game is over
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct TeamRanking {
+    pub gamer_address: String,
+    pub team_id: String,
+    pub team_rank: u64,
+    pub team_points: u64,
+}
+
+/// Running totals of the rewards assigned in a pool over all the batches
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PoolRewardDetails {
+    /// Total reward in FURY assigned to teams so far
+    pub reward_assigned: Uint128,
+
+    /// Ranks already paid out, tied teams have to come in the same batch
+    pub ranks_assigned: Vec<u64>,
+}
+
 /// This is used for transferring tokens to multiple wallets
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
 #[serde(rename_all = "snake_case")]
@@ -175,15 +258,125 @@ pub const GAME_DETAILS: Map<String, GameDetails> =
 pub const POOL_TYPE_DETAILS: Map<String, PoolTypeDetails> =
     Map::new("pool_type_details");
 
-/// Map of pools. The key is pool id and the
-/// PoolDetails will contain information about the pool 
-pub const POOL_DETAILS: Map<String, PoolDetails> =
-    Map::new("pool_details");
+pub struct PoolDetailsIndexes<'a> {
+    /// Pools of a pool type in a game. The key is (game id, pool type, pk)
+    pub pool_type: MultiIndex<'a, (Vec<u8>, Vec<u8>, Vec<u8>), PoolDetails>,
+}
+
+impl<'a> IndexList<PoolDetails> for PoolDetailsIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PoolDetails>> + '_> {
+        let v: Vec<&dyn Index<PoolDetails>> = vec![&self.pool_type];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Map of pools. The key is (game id, pool id) so the pools of a game
+/// can be read through a prefix. The PoolDetails will contain information about the pool
+pub fn pools<'a>() -> IndexedMap<'a, (&'a str, &'a str), PoolDetails, PoolDetailsIndexes<'a>> {
+    let indexes = PoolDetailsIndexes {
+        pool_type: MultiIndex::new(
+            |d: &PoolDetails, pk: Vec<u8>| {
+                (d.game_id.as_bytes().to_vec(), d.pool_type.as_bytes().to_vec(), pk)
+            },
+            "pool_details",
+            "pool_details__pool_type",
+        ),
+    };
+    IndexedMap::new("pool_details", indexes)
+}
+
+pub struct PoolTeamDetailsIndexes<'a> {
+    /// Pools a gamer has teams in. The key is (gamer, game id, pk)
+    pub gamer: MultiIndex<'a, (Vec<u8>, Vec<u8>, Vec<u8>), Vec<PoolTeamDetails>>,
+}
+
+impl<'a> IndexList<Vec<PoolTeamDetails>> for PoolTeamDetailsIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Vec<PoolTeamDetails>>> + '_> {
+        let v: Vec<&dyn Index<Vec<PoolTeamDetails>>> = vec![&self.gamer];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Map of pools and its gamers. the key is (game id, pool id, gamer) and the
+/// PoolTeamDetails will contain the teams of the gamer in the pool
+pub fn pool_teams<'a>() -> IndexedMap<'a, (&'a str, &'a str, &'a str), Vec<PoolTeamDetails>, PoolTeamDetailsIndexes<'a>> {
+    let indexes = PoolTeamDetailsIndexes {
+        gamer: MultiIndex::new(
+            |d: &Vec<PoolTeamDetails>, pk: Vec<u8>| {
+                // All the teams in an entry belong to the same gamer and game
+                let team = d.first().cloned().unwrap_or_default();
+                (team.gamer_address.as_bytes().to_vec(), team.game_id.as_bytes().to_vec(), pk)
+            },
+            "pool_team_details",
+            "pool_team_details__gamer",
+        ),
+    };
+    IndexedMap::new("pool_team_details", indexes)
+}
+
+/// Parameters of the pool type at the time each pool was created, so later
+/// changes to the pool type only apply to new pools. The key is (game id, pool id)
+pub const POOL_PARAMS: Map<(&str, &str), PoolTypeDetails> = Map::new("pool_params");
+
+/// Lets players open their own pools of a pool type. The creator picks the
+/// pool size within the bounds and gets a share of the pool's rake
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct PoolTemplate {
+    pub min_pool_size: u32,
+    pub max_pool_size: u32,
+
+    /// Share of every rake wallet's amount paid to the creator instead, out of 100
+    pub creator_rake_percentage: u32,
+}
+
+/// Map of pool templates. The key is the pool type
+pub const POOL_TEMPLATES: Map<String, PoolTemplate> = Map::new("pool_templates");
+
+/// A pool opened by a player from a pool template
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct UserPoolDetails {
+    pub creator: Addr,
+    pub name: String,
+
+    /// Replaces the pool type's max_teams_for_pool
+    pub max_teams_for_pool: u32,
+    pub creator_rake_percentage: u32,
+}
+
+/// Map of pools created by players. The key is (game id, pool id)
+pub const USER_POOLS: Map<(&str, &str), UserPoolDetails> = Map::new("user_pools");
+
+/// Number of player pools an address opened in a game. The key is (game id, creator)
+pub const USER_POOLS_CREATED: Map<(&str, &str), u32> = Map::new("user_pools_created");
+
+/// Who can enter a private pool. A gamer gets in by being invited or by
+/// sending the code whose sha256 hash was set when the pool was created
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct PoolAccess {
+    /// Manages the invitees until the game is locked
+    pub creator: Addr,
+    pub invitees: Vec<Addr>,
+    pub join_code_hash: Option<Binary>,
+}
+
+/// Map of private pools. The key is (game id, pool id), public pools have no entry
+pub const POOL_ACCESS: Map<(&str, &str), PoolAccess> = Map::new("pool_access");
 
-/// Map of pools and its gamers. the key is pool id and the
-/// PoolBettingDetails will contain information about the betters and amount betted
-pub const POOL_TEAM_DETAILS: Map<(&str, &str), Vec<PoolTeamDetails>> =
-    Map::new("pool_team_details");
+/// Where the winnings of a gamer go and who can claim them for the gamer
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct ClaimSettings {
+    /// Receives the rewards and refunds instead of the gamer
+    pub payout_address: Option<Addr>,
+    /// Can claim for the gamer, without receiving anything
+    pub relayer: Option<Addr>,
+}
+
+/// Map of claim settings. The key is the gamer address
+pub const CLAIM_SETTINGS: Map<&str, ClaimSettings> = Map::new("claim_settings");
 
 pub const CONTRACT_POOL_COUNT: Map<&Addr, Uint128> = Map::new("contract_pool_count");
 
@@ -191,7 +384,8 @@ pub const GAME_RESULT_DUMMY: Map<&Addr, GameResult> = Map::new("game_result");
 
 pub const PLATFORM_WALLET_PERCENTAGES: Map<String, WalletPercentage> = Map::new("platform_wallet_percentages");
 
-pub const CURRENT_REWARD_FOR_POOL: Map<String, Uint128> = Map::new("current_reward_for_pool");
+/// Map of reward totals. The key is (game id, pool id)
+pub const POOL_REWARD_DETAILS: Map<(&str, &str), PoolRewardDetails> = Map::new("pool_reward_details");
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
 #[serde(rename_all = "snake_case")]
@@ -206,4 +400,160 @@ pub struct SwapBalanceDetails {
 // This is a simple store we use to save the balance of the contact
 // pre swap and use it to compute the amount of CW20 tokens gained
 // In the swap
-pub const SWAP_BALANCE_INFO: Map<String, SwapBalanceDetails> = Map::new("current_reward_for_pool");
+pub const SWAP_BALANCE_INFO: Map<String, SwapBalanceDetails> = Map::new("swap_balance_info");
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum SwapStatus {
+    /// Sent to the proxy, waiting for the reply
+    Pending,
+    Succeeded,
+    /// Nothing was swapped, the swap can be sent again
+    Failed,
+}
+
+/// One swap of the UST collected by a pool for FURY
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct SwapAttempt {
+    pub reply_id: u64,
+    pub status: SwapStatus,
+    pub ust_amount: Uint128,
+    pub ust_for_rake: Uint128,
+    /// The swap is reverted when it brings in less FURY than this
+    pub min_fury_received: Uint128,
+    pub balance_pre_swap: Uint128,
+    pub fury_received: Uint128,
+    pub sent_at: Timestamp,
+    /// Error returned by the proxy for failed swaps
+    pub error: Option<String>,
+}
+
+/// Map of all the swaps sent for a pool, oldest first. The key is (game id, pool id)
+pub const SWAP_HISTORY: Map<(&str, &str), Vec<SwapAttempt>> = Map::new("swap_history");
+
+/// Pool a swap reply is for, the reply id is also the one of its SwapAttempt
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct SwapReplyTarget {
+    pub game_id: String,
+    pub pool_id: String,
+}
+
+/// Map of the swaps waiting for their reply. The key is the reply id
+pub const SWAP_REPLIES: Map<U64Key, SwapReplyTarget> = Map::new("swap_replies");
+
+/// Reply id given to the last swap sent
+pub const LAST_SWAP_REPLY_ID: Item<u64> = Item::new("last_swap_reply_id");
+
+/// Running totals of the funds that went in and out of a pool. Amounts are
+/// in the pool type's entry asset, or in UST for pools paid in FURY except
+/// for the rewards which are then in FURY
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PoolLedger {
+    /// Pool fees of all the teams that entered
+    pub collected: Uint128,
+
+    /// UST swapped to FURY for the prize pool
+    pub swapped: Uint128,
+
+    /// Sent to the rake wallets once the final batch is distributed
+    pub rake_paid: Uint128,
+
+    pub rewards_assigned: Uint128,
+    pub rewards_claimed: Uint128,
+    pub refunds_claimed: Uint128,
+}
+
+/// Map of pool ledgers. The key is (game id, pool id)
+pub const POOL_LEDGERS: Map<(&str, &str), PoolLedger> = Map::new("pool_ledgers");
+
+/// What all the pools still owe, per asset. Moved along with every change to a
+/// pool's ledger, swap or distribution so it never has to be summed up
+pub const POOL_OBLIGATIONS: Item<Vec<(Denom, Uint128)>> = Item::new("pool_obligations");
+
+/// Running totals of a gamer over all the pools they entered
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct GamerStats {
+    pub pools_entered: u64,
+    pub teams_entered: u64,
+
+    /// Teams that finished first in their pool
+    pub wins: u64,
+
+    /// Amounts are kept per stake asset. Pools paid in FURY count in UST,
+    /// their FURY rewards at the rate the pool swapped at
+    pub assets: Vec<GamerAssetStats>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct GamerAssetStats {
+    pub asset: Denom,
+
+    /// Pool fees paid
+    pub spent: Uint128,
+
+    /// Rewards assigned, in the stake asset
+    pub won: Uint128,
+
+    /// Refunds claimed and pool fees given back on withdrawal
+    pub refunded: Uint128,
+}
+
+/// Map of gamer stats. The key is the gamer address
+pub const GAMER_STATS: Map<&str, GamerStats> = Map::new("gamer_stats");
+
+/// Pools a gamer was assigned a reward in and has not claimed it yet. The key
+/// is (gamer address, pool id), pool ids being unique across games, and the
+/// value is the game id of the pool
+pub const GAMER_CLAIMABLE_POOLS: Map<(&str, &str), String> = Map::new("gamer_claimable_pools");
+
+/// Pools set to refund a gamer has not claimed the refund of yet. Keyed like
+/// the claimable pools
+pub const GAMER_REFUNDABLE_POOLS: Map<(&str, &str), String> = Map::new("gamer_refundable_pools");
+
+/// Ranked teams of a pool in leaderboard order. The key is (game id, pool id,
+/// leaderboard key) and the value is (gamer address, team id)
+pub const POOL_LEADERBOARD: Map<(&str, &str, &[u8]), (String, String)> = Map::new("pool_leaderboard");
+
+/// Who can veto proposed game results and for how long
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct ResultDisputeConfig {
+    /// Time after a proposal during which the oracles can veto it
+    pub challenge_period: Duration,
+
+    /// Oracles or cw3 multisig contracts allowed to vote on proposed results
+    pub oracles: Vec<String>,
+
+    /// Number of No or Veto votes that reject the proposed results
+    pub veto_threshold: u32,
+}
+
+pub const RESULT_DISPUTE_CONFIG: Item<ResultDisputeConfig> = Item::new("result_dispute_config");
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct ResultVote {
+    pub oracle: String,
+    pub vote: Vote,
+}
+
+/// Results proposed by the admin, waiting for the challenge period to end
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingPoolResults {
+    pub game_winners: Vec<TeamRanking>,
+    pub is_final_batch: bool,
+
+    /// Results can be finalised once this has expired
+    pub challenge_end: Expiration,
+
+    pub votes: Vec<ResultVote>,
+}
+
+/// Map of results waiting to be finalised. The key is (game id, pool id)
+pub const PENDING_POOL_RESULTS: Map<(&str, &str), PendingPoolResults> = Map::new("pending_pool_results");
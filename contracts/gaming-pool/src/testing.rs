@@ -2,15 +2,79 @@
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{coin, Uint128};
-    use cosmwasm_std::Addr;
+    use astroport::asset::{Asset, AssetInfo};
+    use astroport::pair::ExecuteMsg as AstroPortExecute;
+    use cosmwasm_std::{BankMsg, coin, CosmosMsg, StdError, StdResult, to_binary, to_vec, Uint128, WasmMsg};
+    use cosmwasm_std::{Addr, Storage};
+    use cw0::{Duration, Expiration};
+    use cw3::Vote;
+    use cw20::{Cw20ReceiveMsg, Denom};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cw_multi_test::{App, AppBuilder, ContractWrapper, Executor};
+    use cw2::{get_contract_version, set_contract_version};
+
+    use crate::contract::{CLAIMED_REWARD, GAME_CANCELLED, GAME_COMPLETED, GAME_POOL_CLOSED, GAME_POOL_OPEN, CONTRACT_NAME, CONTRACT_VERSION, INITIAL_REFUND_AMOUNT, MAX_POOLS_PER_GAME, instantiate, migrate};
+    use crate::execute::{accept_admin_role, cancel_game, claim_refund, claim_reward, compute_team_rewards, create_game, create_pool, execute_sweep, finalize_game_results, game_pool_bid_submit, game_pool_bid_submit_any_pool, game_pool_reward_distribute, lock_game, occupied_ranks, propose_game_results, propose_new_admin, received_message, save_team_details, set_game_operator, set_platform_fee_wallets, set_pool_type_params, set_result_dispute_params, set_treasury, validate_payout_tiers, vote_game_results, withdraw_game_results};
+    use crate::ContractError;
+    use crate::migrations::{LEGACY_CURRENT_REWARD_FOR_POOL, LEGACY_GAME_DETAILS, LEGACY_POOL_DETAILS, LEGACY_POOL_TEAM_DETAILS, LegacyGameDetails};
+    use crate::msg::{GamePoolBidSubmitCommand, InstantiateMsg, MigrateMsg, ReceivedMsg};
+    use crate::mock_proxy;
+    use crate::oracle::{PriceOracle, ProxyOracle};
+    use crate::query::{get_pool_teams_for_gamer, get_team_count_for_user_in_pool_type, query_all_games, query_all_pools_in_game, query_all_teams, query_game_details, query_pending_game_results, query_pool_details, query_pool_leaderboard, query_reward, query_roles, query_team_details};
+    use crate::state::{CONFIG, PayoutTier, PLATFORM_WALLET_PERCENTAGES, POOL_LEDGERS, POOL_REWARD_DETAILS, PoolDetails, PoolTeamDetails, pool_teams, SWAP_BALANCE_INFO, SwapBalanceDetails, TeamRanking, WalletPercentage};
+
+    // Fixed prices and fees in place of the astroport proxy
+    struct MockPriceOracle {
+        fury_per_ust: u128,
+        swap_fee: Uint128,
+    }
+
+    impl PriceOracle for MockPriceOracle {
+        fn fury_equivalent_to_ust(&self, ust_count: Uint128) -> StdResult<Uint128> {
+            Ok(ust_count.multiply_ratio(self.fury_per_ust, 1u128))
+        }
+
+        fn ust_equivalent_to_fury(&self, fury_count: Uint128) -> StdResult<Uint128> {
+            Ok(fury_count.multiply_ratio(1u128, self.fury_per_ust))
+        }
+
+        fn swap_fee(&self, _swap_message: &AstroPortExecute) -> StdResult<Uint128> {
+            Ok(self.swap_fee)
+        }
+
+        fn tax(&self, _asset: &Asset) -> StdResult<Uint128> {
+            Ok(Uint128::zero())
+        }
+    }
+
+    // One FURY for one UST and no swap fee
+    fn mock_oracle() -> MockPriceOracle {
+        MockPriceOracle {
+            fury_per_ust: 1u128,
+            swap_fee: Uint128::zero(),
+        }
+    }
 
-    use crate::contract::{CLAIMED_REWARD, GAME_CANCELLED, GAME_COMPLETED, GAME_POOL_OPEN, INITIAL_REFUND_AMOUNT, instantiate};
-    use crate::execute::{cancel_game, claim_refund, claim_reward, create_pool, game_pool_bid_submit, game_pool_reward_distribute, lock_game, save_team_details, set_platform_fee_wallets, set_pool_type_params};
-    use crate::msg::InstantiateMsg;
-    use crate::query::{get_team_count_for_user_in_pool_type, query_game_details, query_pool_details, query_team_details};
-    use crate::state::{GameResult, PLATFORM_WALLET_PERCENTAGES, POOL_TEAM_DETAILS, WalletPercentage};
+    // Rank 1 gets 10%, rank 2 gets 20% and rank 3 gets 30% of the prize pool
+    fn mock_payout_tiers() -> Vec<PayoutTier> {
+        let mut payout_tiers: Vec<PayoutTier> = Vec::new();
+        payout_tiers.push(PayoutTier {
+            from_rank: 1u64,
+            to_rank: 1u64,
+            percentage: 1000u32,
+        });
+        payout_tiers.push(PayoutTier {
+            from_rank: 2u64,
+            to_rank: 2u64,
+            percentage: 2000u32,
+        });
+        payout_tiers.push(PayoutTier {
+            from_rank: 3u64,
+            to_rank: 3u64,
+            percentage: 3000u32,
+        });
+        return payout_tiers;
+    }
 
     #[test]
     fn test_create_and_query_game() {
@@ -25,7 +89,6 @@ mod tests {
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
             transaction_fee: transaction_fee,
-            game_id: "Game001".to_string(),
         };
         let adminInfo = mock_info("admin11111", &[]);
         instantiate(
@@ -34,8 +97,15 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
-        let queryRes = query_game_details(&mut deps.storage);
+        let queryRes = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match queryRes {
             Ok(gameDetail) => {
                 assert_eq!(gameDetail.game_id, "Game001".to_string());
@@ -59,7 +129,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             transaction_fee: transaction_fee,
             astro_proxy_address: "ASTROPORT".to_string(),
@@ -71,12 +140,36 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToOne".to_string(),
+            Uint128::from(144262u128),
+            1,
+            2,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
+            None,
+            None,
         );
         let mut poolId = String::new();
 
@@ -90,7 +183,7 @@ mod tests {
             }
         }
 
-        let queryRes = query_pool_details(&mut deps.storage, poolId);
+        let queryRes = query_pool_details(&mut deps.storage, "Game001".to_string(), poolId);
         match queryRes {
             Ok(poolDetail) => {
                 assert_eq!(poolDetail.game_id, "Game001".to_string());
@@ -101,6 +194,61 @@ mod tests {
                 assert_eq!(3, 4);
             }
         }
+
+        // Nor can a pool type that never reaches its minimum be set
+        let err = set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTen".to_string(),
+            Uint128::from(144262u128),
+            11,
+            10,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Minimum teams for pool cannot be more than the maximum".to_string());
+
+        // A pool type without details cannot get a pool
+        let err = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToTen".to_string(),
+            None,
+            None,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: No pool type details found".to_string());
+        let pools = query_all_pools_in_game(&mut deps.storage, "Game001".to_string(), None, None).unwrap();
+        assert_eq!(pools.len(), 1);
+
+        // Games only take so many pools, so they can be locked in one go
+        for _ in 1..MAX_POOLS_PER_GAME {
+            create_pool(
+                deps.as_mut(),
+                mock_env(),
+                adminInfo.clone(),
+                "Game001".to_string(),
+                "oneToOne".to_string(),
+                None,
+                None,
+            ).unwrap();
+        }
+        let err = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
+            None,
+            None,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Game has the most pools allowed".to_string());
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
     }
 
     #[test]
@@ -115,7 +263,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -126,12 +273,36 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToOne".to_string(),
+            Uint128::from(144262u128),
+            1,
+            2,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
+            None,
+            None,
         );
         let mut poolId = String::new();
 
@@ -173,7 +344,7 @@ mod tests {
             }
         }
 
-        let queryRes = query_team_details(&mut deps.storage, poolId.to_string(), teamId.to_string(), "Gamer001".to_string());
+        let queryRes = query_team_details(&mut deps.storage, "Game001".to_string(), poolId.to_string(), teamId.to_string(), "Gamer001".to_string());
         match queryRes {
             Ok(poolTeamDetail) => {
                 assert_eq!(poolTeamDetail.pool_id, poolId.to_string());
@@ -199,7 +370,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -210,6 +380,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
 
@@ -243,13 +420,19 @@ mod tests {
             10,
             2,
             rake_list,
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
+            None,
+            None,
         );
         let mut poolId = String::new();
 
@@ -269,14 +452,16 @@ mod tests {
             mock_env(),
             owner1_info.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             poolId.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
-        let queryRes = query_pool_details(&mut deps.storage, "1".to_string());
+        ,
+            None);
+        let queryRes = query_pool_details(&mut deps.storage, "Game001".to_string(), "1".to_string());
         match queryRes {
             Ok(poolDetail) => {
                 assert_eq!(poolDetail.pool_id, "1".to_string());
@@ -301,7 +486,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -312,6 +496,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
 
@@ -345,13 +536,19 @@ mod tests {
             1,
             1,
             rake_list,
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         let rsp = create_pool(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
+            None,
+            None,
         );
         let mut poolId = String::new();
 
@@ -371,26 +568,30 @@ mod tests {
             mock_env(),
             owner1_info.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             poolId.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             owner1_info.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             poolId.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
-        let queryRes = query_pool_details(&mut deps.storage, "2".to_string());
+        ,
+            None);
+        let queryRes = query_pool_details(&mut deps.storage, "Game001".to_string(), "2".to_string());
         match queryRes {
             Ok(poolDetail) => {
                 // there should not be any pool with id 2
@@ -416,7 +617,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -427,6 +627,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
 
@@ -460,6 +667,9 @@ mod tests {
             10,
             10,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
         set_pool_type_params(
             deps.as_mut(),
@@ -471,6 +681,9 @@ mod tests {
             10,
             10,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -479,7 +692,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -496,7 +712,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "multiple".to_string(),
+            None,
+            None,
         );
         match rsp_2 {
             Ok(rsp_2) => {
@@ -514,7 +733,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
+            None,
+            None,
         );
         match rsp_3 {
             Ok(rsp_3) => {
@@ -528,58 +750,67 @@ mod tests {
 
         let rewardInfo = mock_info("rewardInfo", &[]);
         // Adding multile team to pool_1 for Game001
-        let ownerXInfo = mock_info("Gamer001", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer001", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 assert_eq!(pool_detail_1.current_teams_count, 4u32);
@@ -596,39 +827,45 @@ mod tests {
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "multiple".to_string(),
             pool_id_2.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "multiple".to_string(),
             pool_id_2.to_string(),
             "Team004".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "multiple".to_string(),
             pool_id_2.to_string(),
             "Team005".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_2 = query_pool_details(&mut deps.storage, pool_id_2.to_string());
+        let query_pool_details_2 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_2.to_string());
         match query_pool_details_2 {
             Ok(pool_detail_2) => {
                 assert_eq!(pool_detail_2.current_teams_count, 3u32);
@@ -645,26 +882,30 @@ mod tests {
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
+            "Game001".to_string(),
             "oneToOne".to_string(),
             pool_id_3.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer001".to_string(),
-            "multiple".to_string(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
             pool_id_3.to_string(),
             "Team004".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
-        let query_pool_details_3 = query_pool_details(&mut deps.storage, pool_id_3.to_string());
+        ,
+            None);
+        let query_pool_details_3 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_3.to_string());
         match query_pool_details_3 {
             Ok(pool_detail_3) => {
                 assert_eq!(pool_detail_3.current_teams_count, 2u32);
@@ -689,7 +930,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -700,6 +940,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -732,6 +979,9 @@ mod tests {
             10,
             2,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -740,7 +990,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -752,46 +1005,53 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -817,7 +1077,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -828,6 +1087,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -860,6 +1126,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -868,7 +1137,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -880,46 +1152,53 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -931,39 +1210,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(100u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -979,15 +1249,25 @@ mod tests {
             }
         }
 
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(1000u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results,
             true,
-            true,
-            Uint128::zero(),
         );
 
         match game_pool_reward_distribute_rsp {
@@ -998,7 +1278,7 @@ mod tests {
             }
         }
 
-        let query_game_status_res = query_game_details(&mut deps.storage);
+        let query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
@@ -1008,7 +1288,7 @@ mod tests {
                 assert_eq!(1, 2);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002".as_ref()));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(100u128));
             assert_eq!(team[1].reward_amount, Uint128::from(200u128));
@@ -1028,7 +1308,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -1039,6 +1318,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1071,6 +1357,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -1079,7 +1368,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -1091,23 +1383,26 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("cwtoken11111", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("cwtoken11111", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
         let cancelInfo = mock_info("cancelInfo", &[]);
-        let cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
 
         /*
                 23 Mar 2022, commenting this out because call to proxy cannot be made
@@ -1142,7 +1437,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -1153,6 +1447,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1185,6 +1486,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -1193,7 +1497,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -1205,46 +1512,53 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1256,39 +1570,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(100u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1306,7 +1611,7 @@ mod tests {
 
         let cancelInfo = mock_info("cancelInfo", &[]);
         let game_pool_reward_distribute_rsp =
-            cancel_game(deps.as_mut(), mock_env(), adminInfo.clone());
+            cancel_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
 
         match game_pool_reward_distribute_rsp {
             Ok(game_pool_reward_distribute_rsp) => {}
@@ -1316,7 +1621,7 @@ mod tests {
             }
         }
 
-        let query_game_status_res = query_game_details(&mut deps.storage);
+        let query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_CANCELLED);
@@ -1326,7 +1631,7 @@ mod tests {
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002".as_ref()));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::zero());
             assert_eq!(team[1].reward_amount, Uint128::zero());
@@ -1347,7 +1652,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -1358,6 +1662,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1380,6 +1691,9 @@ mod tests {
         };
         rake_list.push(rake_3);
 
+        // Rank 1 gets half of the prize pool
+        let mut payout_tiers = mock_payout_tiers();
+        payout_tiers[0].percentage = 5000u32;
         set_pool_type_params(
             deps.as_mut(),
             mock_env(),
@@ -1390,6 +1704,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            payout_tiers,
+            None,
+            None,
         );
 
         // create multiple pool
@@ -1398,7 +1715,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -1417,7 +1737,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -1428,46 +1747,53 @@ mod tests {
             rewardInfo.clone(),
             instantiate_msg,
         );
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1479,39 +1805,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(500u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1527,15 +1844,25 @@ mod tests {
             }
         }
 
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(1000u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results,
             true,
-            true,
-            Uint128::zero(),
         );
 
         match game_pool_reward_distribute_rsp {
@@ -1546,7 +1873,7 @@ mod tests {
             }
         }
 
-        let mut query_game_status_res = query_game_details(&mut deps.storage);
+        let mut query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
@@ -1556,7 +1883,7 @@ mod tests {
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002".as_ref()));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(500u128));
             assert_eq!(team[1].reward_amount, Uint128::from(200u128));
@@ -1568,7 +1895,7 @@ mod tests {
                 it succeeds till calculating reward amount = 1000
 
                 let claim_reward_rsp =
-                    claim_reward(deps.as_mut(), owner1_info.clone(), "Gamer002".to_string(), mock_env());
+                    claim_reward(deps.as_mut(), owner1_info.clone(), "Gamer002".to_string(), &mock_oracle(), None, None, None);
                 match claim_reward_rsp {
                     Ok(claim_reward_rsp) => {
                         //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1585,7 +1912,7 @@ mod tests {
                 }
         */
 
-        query_game_status_res = query_game_details(&mut deps.storage);
+        query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
@@ -1596,7 +1923,7 @@ mod tests {
             }
         }
 
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002".as_ref()));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(500u128)); // TODO This reward should be 0 after full functionality working.
             assert_eq!(team[1].reward_amount, Uint128::from(200u128)); // TODO This reward should be 0 after full functionality working.
@@ -1623,7 +1950,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -1634,6 +1960,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -1666,6 +1999,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -1674,7 +2010,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -1686,46 +2025,53 @@ mod tests {
             }
         }
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1737,39 +2083,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(100u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1785,15 +2122,25 @@ mod tests {
             }
         }
 
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(1000u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results,
             true,
-            true,
-            Uint128::zero(),
         );
 
         match game_pool_reward_distribute_rsp {
@@ -1804,7 +2151,7 @@ mod tests {
             }
         }
 
-        let mut query_game_status_res = query_game_details(&mut deps.storage);
+        let mut query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
@@ -1814,7 +2161,7 @@ mod tests {
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_str(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_str(), "Gamer002".as_ref()));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(100u128));
             assert_eq!(team[1].reward_amount, Uint128::from(200u128));
@@ -1826,7 +2173,7 @@ mod tests {
                 it succeeds till calculating reward amount = 600
 
                 let claim_reward_rsp =
-                    claim_reward(deps.as_mut(), owner1_info.clone(), "Gamer002".to_string(), mock_env());
+                    claim_reward(deps.as_mut(), owner1_info.clone(), "Gamer002".to_string(), &mock_oracle(), None, None, None);
                 match claim_reward_rsp {
                     Ok(claim_reward_rsp) => {
                         //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -1842,7 +2189,7 @@ mod tests {
                     }
                 }
         */
-        query_game_status_res = query_game_details(&mut deps.storage);
+        query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
@@ -1853,7 +2200,7 @@ mod tests {
             }
         }
 
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002".as_ref()));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(100u128)); // TODO This reward should be 0 after full functionality working.
             assert_eq!(team[1].reward_amount, Uint128::from(200u128)); // TODO This reward should be 0 after full functionality working.
@@ -1869,7 +2216,7 @@ mod tests {
         /*
                     23 Mar 2022, commenting this out because call to proxy cannot be made
                 let claim_reward_rsp_2 =
-                    claim_reward(deps.as_mut(), owner1_info.clone(), "Gamer002".to_string(), mock_env());
+                    claim_reward(deps.as_mut(), owner1_info.clone(), "Gamer002".to_string(), &mock_oracle(), None, None, None);
                 match claim_reward_rsp_2 {
                     Ok(claim_reward_rsp_2) => {
                         // IT should not come here
@@ -1900,7 +2247,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -1911,9 +2257,16 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
-
-        let mut rake_list: Vec<WalletPercentage> = Vec::new();
-        let rake_1 = WalletPercentage {
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
+
+        let mut rake_list: Vec<WalletPercentage> = Vec::new();
+        let rake_1 = WalletPercentage {
             wallet_address: "rake_1".to_string(),
             wallet_name: "rake_1".to_string(),
             percentage: 1u32,
@@ -1943,6 +2296,9 @@ mod tests {
             20,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -1951,7 +2307,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -1970,51 +2329,57 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2026,39 +2391,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(100u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2073,7 +2429,7 @@ mod tests {
                 assert_eq!(3, 4);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_ref(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002".as_ref()));
         let mut teams = Vec::new();
         match team_details {
             Ok(some_teams) => {
@@ -2102,7 +2458,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -2113,6 +2468,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -2145,6 +2507,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -2153,7 +2518,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -2166,46 +2534,53 @@ mod tests {
         }
         let rewardInfo = mock_info("rewardInfo", &[]);
 
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         println!("This is the value for the  pool_details{:?}", query_pool_details_1);
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
@@ -2217,39 +2592,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(100u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2265,14 +2631,25 @@ mod tests {
             }
         }
 
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(1000u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results,
-            true, true,
-            Uint128::zero(),
+            true,
         );
 
         match game_pool_reward_distribute_rsp {
@@ -2283,7 +2660,7 @@ mod tests {
             }
         }
 
-        let mut query_game_status_res = query_game_details(&mut deps.storage);
+        let mut query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
@@ -2294,7 +2671,7 @@ mod tests {
             }
         }
 
-        let game_cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let game_cancel_rsp = cancel_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
 
         match game_cancel_rsp {
             Ok(game_cancel_rsp) => {
@@ -2323,7 +2700,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -2334,6 +2710,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -2366,6 +2749,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -2374,7 +2760,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -2386,34 +2775,25 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(100u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
@@ -2422,11 +2802,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results.clone(),
             true,
-            true,
-            Uint128::zero(),
         );
 
         match game_pool_reward_distribute_rsp {
@@ -2444,46 +2823,53 @@ mod tests {
         }
 
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2499,11 +2885,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results,
             true,
-            true,
-            Uint128::zero(),
         );
 
         match game_pool_reward_distribute_rsp {
@@ -2534,7 +2919,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -2545,6 +2929,13 @@ mod tests {
             adminInfo.clone(),
             instantiate_msg,
         );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
 
         let mut rake_list: Vec<WalletPercentage> = Vec::new();
         let rake_1 = WalletPercentage {
@@ -2577,6 +2968,9 @@ mod tests {
             10,
             5,
             rake_list.clone(),
+            mock_payout_tiers(),
+            None,
+            None,
         );
 
         // create multiple pool
@@ -2585,7 +2979,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
+            None,
+            None,
         );
         match rsp_1 {
             Ok(rsp_1) => {
@@ -2604,51 +3001,57 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
         let rewardInfo = mock_info("rewardInfo", &[]);
-        let ownerXInfo = mock_info("Gamer002", &[coin(1000, "stake")]);
+        // Covers the platform and transaction fee on the pool fee
+        let ownerXInfo = mock_info("Gamer002", &[coin(5770480, "uusd")]);
         // Adding same team twice in same pool
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team001".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team002".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
         game_pool_bid_submit(
             deps.as_mut(),
             mock_env(),
             ownerXInfo.clone(),
             "Gamer002".to_string(),
+            "Game001".to_string(),
             "oneToTwo".to_string(),
             pool_id_1.to_string(),
             "Team003".to_string(),
             Uint128::from(144262u128) + platform_fee,
-            true,
+            &mock_oracle(),
             None
-        );
+        ,
+            None);
 
-        let query_pool_details_1 = query_pool_details(&mut deps.storage, pool_id_1.to_string());
+        let query_pool_details_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.to_string());
         match query_pool_details_1 {
             Ok(pool_detail_1) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2660,39 +3063,30 @@ mod tests {
             }
         }
 
-        let game_result_1 = GameResult {
+        let game_result_1 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team001".to_string(),
             team_rank: 1u64,
             team_points: 100u64,
-            reward_amount: Uint128::from(100u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_2 = GameResult {
+        let game_result_2 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team002".to_string(),
             team_rank: 2u64,
             team_points: 200u64,
-            reward_amount: Uint128::from(200u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let game_result_3 = GameResult {
+        let game_result_3 = TeamRanking {
             gamer_address: "Gamer002".to_string(),
-            game_id: "Game001".to_string(),
             team_id: "Team003".to_string(),
-            team_rank: 2u64,
+            team_rank: 3u64,
             team_points: 300u64,
-            reward_amount: Uint128::from(300u128),
-            refund_amount: Uint128::from(INITIAL_REFUND_AMOUNT),
         };
-        let mut game_results: Vec<GameResult> = Vec::new();
+        let mut game_results: Vec<TeamRanking> = Vec::new();
         game_results.push(game_result_1);
         game_results.push(game_result_2);
         game_results.push(game_result_3);
 
-        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone());
+        let lock_game_rsp = lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string());
         match lock_game_rsp {
             Ok(lock_game_rsp) => {
                 //Since max allowed team for gamer under this pooltype is 2 so it will not allow 3rd team creation under this pooltype.
@@ -2708,15 +3102,25 @@ mod tests {
             }
         }
 
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(1000u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
         let game_pool_reward_distribute_rsp = game_pool_reward_distribute(
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results.clone(),
             true,
-            true,
-            Uint128::zero(),
         );
 
         match game_pool_reward_distribute_rsp {
@@ -2727,7 +3131,7 @@ mod tests {
             }
         }
 
-        let query_game_status_res = query_game_details(&mut deps.storage);
+        let query_game_status_res = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string());
         match query_game_status_res {
             Ok(query_game_status_res) => {
                 assert_eq!(query_game_status_res.game_status, GAME_COMPLETED);
@@ -2737,7 +3141,7 @@ mod tests {
                 assert_eq!(5, 6);
             }
         }
-        let team_details = POOL_TEAM_DETAILS.load(&mut deps.storage, (pool_id_1.as_str(), "Gamer002".as_ref()));
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_str(), "Gamer002".as_ref()));
         for team in team_details {
             assert_eq!(team[0].reward_amount, Uint128::from(100u128));
             assert_eq!(team[1].reward_amount, Uint128::from(200u128));
@@ -2748,11 +3152,10 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             adminInfo.clone(),
+            "Game001".to_string(),
             pool_id_1.to_string(),
             game_results,
             true,
-            true,
-            Uint128::zero(),
         );
 
         match game_pool_reward_distribute_rsp_2 {
@@ -2781,7 +3184,6 @@ mod tests {
             minting_contract_address: "cwtoken11111".to_string(),
             admin_address: "admin11111".to_string(),
             platform_fee: platform_fee,
-            game_id: "Game001".to_string(),
             platform_fees_collector_wallet: "FEE_WALLET".to_string(),
             astro_proxy_address: "ASTROPORT".to_string(),
         };
@@ -2818,5 +3220,1713 @@ mod tests {
             assert_eq!(wallet.wallet_name, "rake_3".to_string());
         }
     }
-}
 
+    #[test]
+    fn test_multiple_games_in_single_contract() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game002".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+
+        // Same game cannot be created twice
+        let rsp = create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Game already exists".to_string());
+            }
+        }
+
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToOne".to_string(),
+            Uint128::from(144262u128),
+            2,
+            10,
+            2,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+
+        let rsp_1 = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id_1 = rsp_1.attributes[0].value.clone();
+        let rsp_2 = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game002".to_string(),
+            "oneToOne".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id_2 = rsp_2.attributes[0].value.clone();
+
+        let game_1_pools = query_all_pools_in_game(&mut deps.storage, "Game001".to_string(), None, None).unwrap();
+        assert_eq!(game_1_pools.len(), 1);
+        assert_eq!(game_1_pools[0].pool_id, pool_id_1);
+        let game_2_pools = query_all_pools_in_game(&mut deps.storage, "Game002".to_string(), None, None).unwrap();
+        assert_eq!(game_2_pools.len(), 1);
+        assert_eq!(game_2_pools[0].pool_id, pool_id_2);
+
+        // A pool is only visible under the game it was created for
+        let query_res = query_pool_details(&mut deps.storage, "Game002".to_string(), pool_id_1.clone());
+        assert!(query_res.is_err());
+
+        // Locking one game leaves the other game open
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        let game_1 = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string()).unwrap();
+        assert_eq!(game_1.game_status, GAME_POOL_CLOSED);
+        let game_2 = query_game_details(&mut deps.storage, &mock_env().block, "Game002".to_string()).unwrap();
+        assert_eq!(game_2.game_status, GAME_POOL_OPEN);
+
+        // Pool in the locked game is flagged for refund, the other one is untouched
+        let pool_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.clone()).unwrap();
+        assert_eq!(pool_1.pool_refund_status, true);
+        let pool_2 = query_pool_details(&mut deps.storage, "Game002".to_string(), pool_id_2.clone()).unwrap();
+        assert_eq!(pool_2.pool_refund_status, false);
+
+        let all_games = query_all_games(&mut deps.storage, &mock_env().block, None, None).unwrap();
+        assert_eq!(all_games.len(), 2);
+    }
+
+    #[test]
+    fn test_bids_rejected_after_entry_close_time() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+
+        // Entry close time must be in the future
+        let rsp = create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::AtTime(mock_env().block.time),
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Entry close time is already past".to_string());
+            }
+        }
+
+        let kickoff = mock_env().block.time.plus_seconds(100);
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::AtTime(kickoff),
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToOne".to_string(),
+            Uint128::from(144262u128),
+            2,
+            10,
+            2,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        let rsp = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id = rsp.attributes[0].value.clone();
+
+        let mut after_kickoff = mock_env();
+        after_kickoff.block.time = kickoff;
+
+        // Game is reported closed without any admin transaction
+        let game = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string()).unwrap();
+        assert_eq!(game.game_status, GAME_POOL_OPEN);
+        let game = query_game_details(&mut deps.storage, &after_kickoff.block, "Game001".to_string()).unwrap();
+        assert_eq!(game.game_status, GAME_POOL_CLOSED);
+
+        let rsp = game_pool_bid_submit(
+            deps.as_mut(),
+            after_kickoff.clone(),
+            mock_info("Gamer001", &[coin(5770480, "uusd")]),
+            "Gamer001".to_string(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
+            pool_id.clone(),
+            "Team001".to_string(),
+            Uint128::from(144262u128),
+            &mock_oracle(),
+            None,
+            None,
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(2, 3);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Game is not open for bidding".to_string());
+            }
+        }
+        let pool = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id.clone()).unwrap();
+        assert_eq!(pool.current_teams_count, 0u32);
+
+        let rsp = create_pool(
+            deps.as_mut(),
+            after_kickoff.clone(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
+            None,
+            None,
+        );
+        assert!(rsp.is_err());
+
+        // Admin lock is still used to settle the refund flags
+        let lock_game_rsp = lock_game(deps.as_mut(), after_kickoff.clone(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        assert_eq!(lock_game_rsp.attributes[1].value.clone(), "GAME_POOL_CLOSED".to_string());
+        let pool = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id.clone()).unwrap();
+        assert_eq!(pool.pool_refund_status, true);
+    }
+
+    #[test]
+    fn test_bid_submit_through_cw20_receive() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToOne".to_string(),
+            Uint128::from(144262u128),
+            1,
+            2,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "cheap".to_string(),
+            Uint128::from(10u128),
+            1,
+            2,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            Some(Denom::Cw20(Addr::unchecked("cheaptoken"))),
+            None,
+        ).unwrap();
+        create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
+            None,
+            None,
+        ).unwrap();
+
+        let bid = ReceivedMsg::GamePoolBidSubmit(GamePoolBidSubmitCommand {
+            gamer: "Gamer001".to_string(),
+            game_id: "Game001".to_string(),
+            pool_type: "oneToOne".to_string(),
+            pool_id: "1".to_string(),
+            team_id: "Team001".to_string(),
+            max_spread: None,
+            join_code: None,
+        });
+
+        // Only the FURY token contract can deliver a bid
+        let rsp = received_message(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("othertoken", &[]),
+            Cw20ReceiveMsg {
+                sender: "Gamer001".to_string(),
+                amount: Uint128::from(144262u128),
+                msg: to_binary(&bid).unwrap(),
+            },
+            &mock_oracle(),
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e, ContractError::Unauthorized { invoker: "othertoken".to_string() });
+            }
+        }
+
+        // The wallet that sent the tokens must be the gamer in the bid
+        let rsp = received_message(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cwtoken11111", &[]),
+            Cw20ReceiveMsg {
+                sender: "Gamer002".to_string(),
+                amount: Uint128::from(144262u128),
+                msg: to_binary(&bid).unwrap(),
+            },
+            &mock_oracle(),
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(2, 3);
+            }
+            Err(e) => {
+                assert_eq!(e, ContractError::Unauthorized { invoker: "Gamer002".to_string() });
+            }
+        }
+
+        // A cheap token cannot bid into a FURY pool by naming its own pool type
+        let cheap_bid = ReceivedMsg::GamePoolBidSubmit(GamePoolBidSubmitCommand {
+            gamer: "Gamer001".to_string(),
+            game_id: "Game001".to_string(),
+            pool_type: "cheap".to_string(),
+            pool_id: "1".to_string(),
+            team_id: "Team001".to_string(),
+            max_spread: None,
+            join_code: None,
+        });
+        let err = received_message(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cheaptoken", &[]),
+            Cw20ReceiveMsg {
+                sender: "Gamer001".to_string(),
+                amount: Uint128::from(144262u128),
+                msg: to_binary(&cheap_bid).unwrap(),
+            },
+            &mock_oracle(),
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Pool type does not match the pool".to_string());
+        let err = received_message(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("cheaptoken", &[]),
+            Cw20ReceiveMsg {
+                sender: "Gamer001".to_string(),
+                amount: Uint128::from(144262u128),
+                msg: to_binary(&bid).unwrap(),
+            },
+            &mock_oracle(),
+        ).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "cheaptoken".to_string() });
+        let pool_details = query_pool_details(&mut deps.storage, "Game001".to_string(), "1".to_string()).unwrap();
+        assert_eq!(pool_details.pool_type, "oneToOne".to_string());
+        assert_eq!(pool_details.current_teams_count, 0u32);
+    }
+
+    #[test]
+    fn test_compute_team_rewards_from_rankings() {
+        let payout_tiers = mock_payout_tiers();
+        let prize_pool = Uint128::from(1000u128);
+
+        // Two teams tied at rank 2 share the rank 2 and rank 3 payouts
+        let mut game_winners: Vec<TeamRanking> = Vec::new();
+        game_winners.push(TeamRanking {
+            gamer_address: "Gamer001".to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 1u64,
+            team_points: 300u64,
+        });
+        game_winners.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team002".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+        });
+        game_winners.push(TeamRanking {
+            gamer_address: "Gamer003".to_string(),
+            team_id: "Team003".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+        });
+        let rewards = compute_team_rewards(&payout_tiers, &game_winners, prize_pool, &Vec::new()).unwrap();
+        assert_eq!(rewards[0], Uint128::from(100u128));
+        assert_eq!(rewards[1], Uint128::from(250u128));
+        assert_eq!(rewards[2], Uint128::from(250u128));
+
+        // Ranks paid out in an earlier batch cannot be paid again
+        let mut ranks_assigned: Vec<u64> = Vec::new();
+        ranks_assigned.push(1u64);
+        let rsp = compute_team_rewards(&payout_tiers, &game_winners, prize_pool, &ranks_assigned);
+        match rsp {
+            Ok(_) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Rank already assigned in an earlier batch".to_string());
+            }
+        }
+
+        // Tiers paying out more than the prize pool are rejected
+        let mut payout_tiers = mock_payout_tiers();
+        payout_tiers.push(PayoutTier {
+            from_rank: 4u64,
+            to_rank: 5u64,
+            percentage: 2500u32,
+        });
+        let rsp = validate_payout_tiers(&payout_tiers);
+        match rsp {
+            Ok(_) => {
+                assert_eq!(2, 3);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Payout tiers add up to more than 100 percent".to_string());
+            }
+        }
+
+        // Overlapping tiers are rejected
+        let mut payout_tiers = mock_payout_tiers();
+        payout_tiers[2].from_rank = 2u64;
+        let rsp = validate_payout_tiers(&payout_tiers);
+        match rsp {
+            Ok(_) => {
+                assert_eq!(3, 4);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Payout tiers overlap".to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_tied_ranks_across_batches() {
+        let payout_tiers = mock_payout_tiers();
+        let prize_pool = Uint128::from(1000u128);
+
+        // Two teams tied at rank 2 take up ranks 2 and 3
+        let mut first_batch: Vec<TeamRanking> = Vec::new();
+        first_batch.push(TeamRanking {
+            gamer_address: "Gamer001".to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+        });
+        first_batch.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team002".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+        });
+        compute_team_rewards(&payout_tiers, &first_batch, prize_pool, &Vec::new()).unwrap();
+        let ranks_assigned = occupied_ranks(&first_batch).unwrap();
+        assert_eq!(ranks_assigned, vec![2u64, 3u64]);
+
+        // A later batch cannot be paid the rank 3 tier again
+        let mut second_batch: Vec<TeamRanking> = Vec::new();
+        second_batch.push(TeamRanking {
+            gamer_address: "Gamer003".to_string(),
+            team_id: "Team003".to_string(),
+            team_rank: 3u64,
+            team_points: 100u64,
+        });
+        let err = compute_team_rewards(&payout_tiers, &second_batch, prize_pool, &ranks_assigned).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Rank already assigned in an earlier batch".to_string());
+
+        // Nor can a batch put a team on a rank its own tied teams hold
+        first_batch.push(TeamRanking {
+            gamer_address: "Gamer003".to_string(),
+            team_id: "Team003".to_string(),
+            team_rank: 3u64,
+            team_points: 100u64,
+        });
+        let err = compute_team_rewards(&payout_tiers, &first_batch, prize_pool, &Vec::new()).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Rank is taken by tied teams of the batch".to_string());
+    }
+
+    #[test]
+    fn test_game_results_dispute_window() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            2,
+            10,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        let rsp = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id_1 = rsp.attributes[0].value.clone();
+        for team_id in ["Team001", "Team002"].iter() {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("Gamer002", &[coin(5770480, "uusd")]),
+                "Gamer002".to_string(),
+                "Game001".to_string(),
+                "oneToTwo".to_string(),
+                pool_id_1.clone(),
+                team_id.to_string(),
+                Uint128::from(144262u128) + platform_fee,
+                &mock_oracle(),
+                None,
+                None,
+            );
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(1000u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
+
+        set_result_dispute_params(
+            deps.as_mut(),
+            adminInfo.clone(),
+            Duration::Time(100),
+            vec!["oracle001".to_string(), "oracle002".to_string()],
+            1u32,
+        ).unwrap();
+
+        let mut game_results: Vec<TeamRanking> = Vec::new();
+        game_results.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 1u64,
+            team_points: 100u64,
+        });
+        game_results.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team002".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+        });
+        propose_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results.clone(),
+            true,
+        ).unwrap();
+
+        // Results cannot be finalised during the challenge period
+        let rsp = finalize_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Challenge period has not ended yet".to_string());
+            }
+        }
+
+        // Only oracles can vote
+        let rsp = vote_game_results(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Gamer002", &[]),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            Vote::Veto,
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(2, 3);
+            }
+            Err(e) => {
+                assert_eq!(e, ContractError::Unauthorized { invoker: "Gamer002".to_string() });
+            }
+        }
+
+        // A veto drops the proposed results and nothing is assigned
+        let rsp = vote_game_results(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("oracle001", &[]),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            Vote::Veto,
+        ).unwrap();
+        assert_eq!(rsp.attributes[0].value.clone(), "RESULTS_VETOED".to_string());
+        assert!(query_pending_game_results(&mut deps.storage, "Game001".to_string(), pool_id_1.clone()).is_err());
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002")).unwrap();
+        assert_eq!(team_details[0].reward_amount, Uint128::zero());
+
+        // Results that are not vetoed are applied once the challenge period ends
+        propose_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results,
+            true,
+        ).unwrap();
+        vote_game_results(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("oracle002", &[]),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            Vote::Yes,
+        ).unwrap();
+        let mut after_challenge = mock_env();
+        after_challenge.block.time = after_challenge.block.time.plus_seconds(100);
+        let rsp = vote_game_results(
+            deps.as_mut(),
+            after_challenge.clone(),
+            mock_info("oracle001", &[]),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            Vote::Veto,
+        );
+        assert!(rsp.is_err());
+        finalize_game_results(
+            deps.as_mut(),
+            after_challenge.clone(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+        ).unwrap();
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002")).unwrap();
+        assert_eq!(team_details[0].reward_amount, Uint128::from(100u128));
+        assert_eq!(team_details[1].reward_amount, Uint128::from(200u128));
+        let game = query_game_details(&mut deps.storage, &after_challenge.block, "Game001".to_string()).unwrap();
+        assert_eq!(game.game_status, GAME_COMPLETED);
+    }
+
+    #[test]
+    fn test_no_results_for_pool_flagged_for_refund() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: Uint128::from(100000u128),
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            2,
+            10,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        let rsp = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id_1 = rsp.attributes[0].value.clone();
+        game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Gamer002", &[coin(5770480, "uusd")]),
+            "Gamer002".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            pool_id_1.clone(),
+            "Team001".to_string(),
+            Uint128::from(144262u128) + platform_fee,
+            &mock_oracle(),
+            None,
+            None,
+        ).unwrap();
+
+        // A single team is below the minimum, so the pool is refunded at lock
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+        let pool = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.clone()).unwrap();
+        assert!(pool.pool_refund_status);
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(100u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
+
+        let game_results = vec![TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 1u64,
+            team_points: 100u64,
+        }];
+        let err = propose_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results.clone(),
+            true,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Pool is flagged for refund".to_string());
+        let err = game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results,
+            true,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Pool is flagged for refund".to_string());
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002")).unwrap();
+        assert_eq!(team_details[0].reward_amount, Uint128::zero());
+    }
+
+    #[test]
+    fn test_withdraw_pending_game_results() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: Uint128::from(100000u128),
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            2,
+            10,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        let rsp = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id_1 = rsp.attributes[0].value.clone();
+        for team_id in ["Team001", "Team002"].iter() {
+            game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("Gamer002", &[coin(5770480, "uusd")]),
+                "Gamer002".to_string(),
+                "Game001".to_string(),
+                "oneToTwo".to_string(),
+                pool_id_1.clone(),
+                team_id.to_string(),
+                Uint128::from(144262u128) + platform_fee,
+                &mock_oracle(),
+                None,
+                None,
+            );
+        }
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+
+        let mut game_results: Vec<TeamRanking> = Vec::new();
+        game_results.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 1u64,
+            team_points: 100u64,
+        });
+        game_results.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team002".to_string(),
+            team_rank: 2u64,
+            team_points: 200u64,
+        });
+
+        // Results that could never be distributed are refused up front
+        let err = propose_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results.clone(),
+            true,
+        ).unwrap_err();
+        assert_eq!(err, ContractError::SwapInfoNotFound {});
+
+        SWAP_BALANCE_INFO.save(
+            &mut deps.storage,
+            pool_id_1.clone(),
+            &SwapBalanceDetails {
+                balance_pre_swap: Uint128::zero(),
+                balance_post_swap: Uint128::from(1000u128),
+                exchange_rate: Uint128::from(10000u128),
+                ust_amount_swapped: Uint128::from(1000u128),
+                ust_for_rake: Uint128::zero(),
+            },
+        );
+        let mut same_rank = game_results.clone();
+        same_rank[1].team_rank = 1u64;
+        same_rank.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team003".to_string(),
+            team_rank: 2u64,
+            team_points: 50u64,
+        });
+        let err = propose_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            same_rank,
+            true,
+        ).unwrap_err();
+        assert_eq!(err.to_string(), "Generic error: Rank is taken by tied teams of the batch".to_string());
+
+        // A pending proposal can be withdrawn by the operator and proposed again
+        propose_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results.clone(),
+            false,
+        ).unwrap();
+        let err = withdraw_game_results(
+            deps.as_mut(),
+            mock_info("Gamer002", &[]),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+        ).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "Gamer002".to_string() });
+        let rsp = withdraw_game_results(
+            deps.as_mut(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+        ).unwrap();
+        assert_eq!(rsp.attributes[0].value.clone(), "RESULTS_WITHDRAWN".to_string());
+        assert!(query_pending_game_results(&mut deps.storage, "Game001".to_string(), pool_id_1.clone()).is_err());
+        assert!(withdraw_game_results(
+            deps.as_mut(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+        ).is_err());
+
+        propose_game_results(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results,
+            true,
+        ).unwrap();
+        let mut after_challenge = mock_env();
+        after_challenge.block.time = after_challenge.block.time.plus_seconds(1);
+        finalize_game_results(
+            deps.as_mut(),
+            after_challenge,
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+        ).unwrap();
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", pool_id_1.as_ref(), "Gamer002")).unwrap();
+        assert_eq!(team_details[0].reward_amount, Uint128::from(100u128));
+        assert_eq!(team_details[1].reward_amount, Uint128::from(200u128));
+    }
+
+    #[test]
+    fn test_bid_submit_any_pool_opens_new_pool_when_full() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            2,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToOne".to_string(),
+            Uint128::from(144262u128),
+            1,
+            2,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        // A pool of another type must not be picked
+        create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToOne".to_string(),
+            None,
+            None,
+        ).unwrap();
+
+        for team_id in ["Team001", "Team002", "Team003"].iter() {
+            game_pool_bid_submit_any_pool(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("Gamer002", &[coin(5770480, "uusd")]),
+                "Gamer002".to_string(),
+                "Game001".to_string(),
+                "oneToTwo".to_string(),
+                team_id.to_string(),
+                Uint128::from(144262u128) + platform_fee,
+                &mock_oracle(),
+                None,
+            );
+        }
+
+        let pool_1 = query_pool_details(&mut deps.storage, "Game001".to_string(), "1".to_string()).unwrap();
+        assert_eq!(pool_1.current_teams_count, 0u32);
+        let pool_2 = query_pool_details(&mut deps.storage, "Game001".to_string(), "2".to_string()).unwrap();
+        assert_eq!(pool_2.pool_type, "oneToTwo".to_string());
+        assert_eq!(pool_2.current_teams_count, 2u32);
+        let pool_3 = query_pool_details(&mut deps.storage, "Game001".to_string(), "3".to_string()).unwrap();
+        assert_eq!(pool_3.pool_type, "oneToTwo".to_string());
+        assert_eq!(pool_3.current_teams_count, 1u32);
+        let team_details = pool_teams().load(&mut deps.storage, ("Game001", "3", "Gamer002")).unwrap();
+        assert_eq!(team_details[0].team_id, "Team003".to_string());
+
+        // Bids for a given pool still fail once it is full
+        let rsp = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Gamer002", &[coin(5770480, "uusd")]),
+            "Gamer002".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            "2".to_string(),
+            "Team004".to_string(),
+            Uint128::from(144262u128) + platform_fee,
+            &mock_oracle(),
+            None,
+            None,
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: pool max team limit reached ".to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_paginated_pools_and_gamer_pool_index() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300000u128);
+        let transaction_fee = Uint128::from(100000u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        for game_id in ["Game001", "Game002"].iter() {
+            create_game(
+                deps.as_mut(),
+                mock_env(),
+                adminInfo.clone(),
+                game_id.to_string(),
+                Expiration::Never {},
+            ).unwrap();
+        }
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(144262u128),
+            1,
+            2,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        for _ in 0..3 {
+            create_pool(
+                deps.as_mut(),
+                mock_env(),
+                adminInfo.clone(),
+                "Game001".to_string(),
+                "oneToTwo".to_string(),
+                None,
+                None,
+            ).unwrap();
+        }
+
+        let first_page = query_all_pools_in_game(&mut deps.storage, "Game001".to_string(), None, Some(2)).unwrap();
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(first_page[0].pool_id, "1".to_string());
+        assert_eq!(first_page[1].pool_id, "2".to_string());
+        let second_page = query_all_pools_in_game(&mut deps.storage, "Game001".to_string(), Some("2".to_string()), Some(2)).unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].pool_id, "3".to_string());
+
+        let games = query_all_games(&mut deps.storage, &mock_env().block, Some("Game001".to_string()), None).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].game_id, "Game002".to_string());
+
+        save_team_details(
+            &mut deps.storage,
+            mock_env(),
+            "Gamer001".to_string(),
+            "1".to_string(),
+            "Team001".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            Uint128::from(100u128),
+            false,
+            Uint128::zero(),
+            false,
+            0u64,
+            1u64,
+        ).unwrap();
+        save_team_details(
+            &mut deps.storage,
+            mock_env(),
+            "Gamer002".to_string(),
+            "3".to_string(),
+            "Team002".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            Uint128::from(200u128),
+            false,
+            Uint128::zero(),
+            false,
+            0u64,
+            1u64,
+        ).unwrap();
+
+        // Only the pools the gamer entered are read
+        let gamer_pools = get_pool_teams_for_gamer(&mut deps.storage, "Gamer002".to_string()).unwrap();
+        assert_eq!(gamer_pools.len(), 1);
+        assert_eq!(gamer_pools[0][0].pool_id, "3".to_string());
+        assert_eq!(query_reward(&mut deps.storage, "Gamer001".to_string()).unwrap(), Uint128::from(100u128));
+        assert_eq!(query_reward(&mut deps.storage, "Gamer002".to_string()).unwrap(), Uint128::from(200u128));
+
+        let teams = query_all_teams(&mut deps.storage, "Game001".to_string(), vec!["Gamer001".to_string(), "Gamer002".to_string()]).unwrap();
+        assert_eq!(teams.len(), 2);
+        let teams = query_all_teams(&mut deps.storage, "Game002".to_string(), vec!["Gamer001".to_string()]).unwrap();
+        assert_eq!(teams.len(), 0);
+    }
+
+    #[test]
+    fn test_pool_with_native_entry_asset() {
+        let mut deps = mock_dependencies(&[]);
+        let platform_fee = Uint128::from(300u128);
+        let transaction_fee = Uint128::from(100u128);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: transaction_fee,
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: platform_fee,
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        let mut rake_list: Vec<WalletPercentage> = Vec::new();
+        rake_list.push(WalletPercentage {
+            wallet_address: "rake_1".to_string(),
+            wallet_name: "rake_1".to_string(),
+            percentage: 10u32,
+        });
+        let mut payout_tiers: Vec<PayoutTier> = Vec::new();
+        payout_tiers.push(PayoutTier {
+            from_rank: 1u64,
+            to_rank: 1u64,
+            percentage: 5000u32,
+        });
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(1000000u128),
+            2,
+            10,
+            5,
+            rake_list,
+            payout_tiers,
+            Some(Denom::Native("uluna".to_string())),
+            None,
+        ).unwrap();
+        let rsp = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id_1 = rsp.attributes[0].value.clone();
+
+        // The entry fee plus 3% platform and 1% transaction fee, all in uluna
+        let rsp = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Gamer002", &[coin(1000000, "uluna")]),
+            "Gamer002".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            pool_id_1.clone(),
+            "Team001".to_string(),
+            Uint128::from(1000000u128),
+            &mock_oracle(),
+            None,
+            None,
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e, ContractError::InvalidEntryFunds {});
+            }
+        }
+        for team_id in ["Team001", "Team002"].iter() {
+            let rsp = game_pool_bid_submit(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("Gamer002", &[coin(1040000, "uluna")]),
+                "Gamer002".to_string(),
+                "Game001".to_string(),
+                "oneToTwo".to_string(),
+                pool_id_1.clone(),
+                team_id.to_string(),
+                Uint128::from(1040000u128),
+                &mock_oracle(),
+                None,
+                None,
+            ).unwrap();
+            assert_eq!(rsp.messages[0].msg, CosmosMsg::Bank(BankMsg::Send {
+                to_address: "FEE_WALLET".to_string(),
+                amount: vec![coin(40000, "uluna")],
+            }));
+        }
+        let pool_details = query_pool_details(&mut deps.storage, "Game001".to_string(), pool_id_1.clone()).unwrap();
+        assert_eq!(pool_details.current_teams_count, 2u32);
+
+        lock_game(deps.as_mut(), mock_env(), adminInfo.clone(), "Game001".to_string()).unwrap();
+
+        // No swap is needed, the rake is paid out of the uluna collection
+        let mut game_results: Vec<TeamRanking> = Vec::new();
+        game_results.push(TeamRanking {
+            gamer_address: "Gamer002".to_string(),
+            team_id: "Team001".to_string(),
+            team_rank: 1u64,
+            team_points: 100u64,
+        });
+        let rsp = game_pool_reward_distribute(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            pool_id_1.clone(),
+            game_results,
+            true,
+        ).unwrap();
+        assert_eq!(rsp.messages[0].msg, CosmosMsg::Bank(BankMsg::Send {
+            to_address: "rake_1".to_string(),
+            amount: vec![coin(200000, "uluna")],
+        }));
+
+        let rsp = claim_reward(
+            deps.as_mut(),
+            mock_info("Gamer002", &[]),
+            "Gamer002".to_string(),
+            &mock_oracle(),
+            None,
+            None,
+            None,
+        ).unwrap();
+        assert_eq!(rsp.messages.len(), 1);
+        assert_eq!(rsp.messages[0].msg, CosmosMsg::Bank(BankMsg::Send {
+            to_address: "Gamer002".to_string(),
+            amount: vec![coin(900000, "uluna")],
+        }));
+    }
+
+    #[test]
+    fn test_bid_priced_by_oracle_and_mock_proxy() {
+        let mut deps = mock_dependencies(&[]);
+        let instantiate_msg = InstantiateMsg {
+            transaction_fee: Uint128::from(100u128),
+            minting_contract_address: "cwtoken11111".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: Uint128::from(300u128),
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            instantiate_msg,
+        );
+        create_game(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            Expiration::Never {},
+        ).unwrap();
+        set_pool_type_params(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "oneToTwo".to_string(),
+            Uint128::from(1000u128),
+            2,
+            10,
+            5,
+            vec![],
+            mock_payout_tiers(),
+            None,
+            None,
+        ).unwrap();
+        let rsp = create_pool(
+            deps.as_mut(),
+            mock_env(),
+            adminInfo.clone(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            None,
+            None,
+        ).unwrap();
+        let pool_id_1 = rsp.attributes[0].value.clone();
+
+        // Ten FURY per UST, so the 1000 UST pool fee is 10000 FURY
+        let oracle = MockPriceOracle {
+            fury_per_ust: 10u128,
+            swap_fee: Uint128::from(25u128),
+        };
+        let rsp = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Gamer001", &[coin(40, "uusd")]),
+            "Gamer001".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            pool_id_1.clone(),
+            "Team001".to_string(),
+            Uint128::from(9000u128),
+            &oracle,
+            None,
+            None,
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(1, 2);
+            }
+            Err(e) => {
+                assert_eq!(e.to_string(), "Generic error: Amount being bid does not match the pool fee and the platform fee".to_string());
+            }
+        }
+
+        // 3% platform and 1% transaction fee on the pool fee, paid in UST
+        let rsp = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Gamer001", &[coin(39, "uusd")]),
+            "Gamer001".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            pool_id_1.clone(),
+            "Team001".to_string(),
+            Uint128::from(10000u128),
+            &oracle,
+            None,
+            None,
+        );
+        match rsp {
+            Ok(_rsp) => {
+                assert_eq!(2, 3);
+            }
+            Err(e) => {
+                assert_eq!(e, ContractError::InsufficientFeesUst {});
+            }
+        }
+
+        // The swap through the proxy carries the swap fee
+        let rsp = game_pool_bid_submit(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("Gamer001", &[coin(40, "uusd")]),
+            "Gamer001".to_string(),
+            "Game001".to_string(),
+            "oneToTwo".to_string(),
+            pool_id_1.clone(),
+            "Team001".to_string(),
+            Uint128::from(10000u128),
+            &oracle,
+            None,
+            None,
+        ).unwrap();
+        assert_eq!(rsp.messages.len(), 3);
+        match rsp.messages[2].msg.clone() {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, funds, .. }) => {
+                assert_eq!(contract_addr, "ASTROPORT".to_string());
+                assert_eq!(funds, vec![coin(25, "uusd")]);
+            }
+            _ => {
+                assert_eq!(3, 4);
+            }
+        }
+
+        // The mock proxy contract gives the same prices on chain
+        let mut app: App = AppBuilder::new().build();
+        let proxy_code_id = app.store_code(Box::new(ContractWrapper::new(
+            mock_proxy::execute,
+            mock_proxy::instantiate,
+            mock_proxy::query,
+        )));
+        let proxy_address = app.instantiate_contract(
+            proxy_code_id,
+            Addr::unchecked("admin11111"),
+            &mock_proxy::MockProxyConfig {
+                fury_token_address: "cwtoken11111".to_string(),
+                fury_per_ust: Uint128::from(10u128),
+                swap_fee: Uint128::from(25u128),
+            },
+            &[],
+            "astroport proxy",
+            None,
+        ).unwrap();
+        let proxy_oracle = ProxyOracle::new(app.wrap(), proxy_address.to_string());
+        assert_eq!(proxy_oracle.fury_equivalent_to_ust(Uint128::from(1000u128)).unwrap(), Uint128::from(10000u128));
+        assert_eq!(proxy_oracle.ust_equivalent_to_fury(Uint128::from(10000u128)).unwrap(), Uint128::from(1000u128));
+        let swap_message = AstroPortExecute::Swap {
+            offer_asset: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                amount: Uint128::from(1000u128),
+            },
+            belief_price: None,
+            max_spread: None,
+            to: None,
+        };
+        assert_eq!(proxy_oracle.swap_fee(&swap_message).unwrap(), Uint128::from(25u128));
+    }
+
+    #[test]
+    fn test_admin_handover_and_roles() {
+        let mut deps = mock_dependencies(&[]);
+        let instantiate_msg = InstantiateMsg {
+            minting_contract_address: "cwtoken11111".to_string(),
+            platform_fees_collector_wallet: "FEE_WALLET".to_string(),
+            astro_proxy_address: "ASTROPORT".to_string(),
+            admin_address: "admin11111".to_string(),
+            platform_fee: Uint128::from(300000u128),
+            transaction_fee: Uint128::from(100000u128),
+        };
+        let adminInfo = mock_info("admin11111", &[]);
+        let newAdminInfo = mock_info("admin22222", &[]);
+        let operatorInfo = mock_info("operator11111", &[]);
+        let treasuryInfo = mock_info("treasury11111", &[]);
+        instantiate(deps.as_mut(), mock_env(), adminInfo.clone(), instantiate_msg).unwrap();
+
+        let roles = query_roles(deps.as_ref()).unwrap();
+        assert_eq!(roles.admin, Some("admin11111".to_string()));
+        assert_eq!(roles.game_operator, Some("admin11111".to_string()));
+        assert_eq!(roles.treasury, Some("admin11111".to_string()));
+        assert_eq!(roles.pending_admin, None);
+
+        // Only the super-admin hands out roles
+        let err = set_game_operator(deps.as_mut(), operatorInfo.clone(), "operator11111".to_string()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "operator11111".to_string() });
+        set_game_operator(deps.as_mut(), adminInfo.clone(), "operator11111".to_string()).unwrap();
+        set_treasury(deps.as_mut(), adminInfo.clone(), "treasury11111".to_string()).unwrap();
+
+        // The operator runs games but cannot sweep, the treasury the other way round
+        create_game(deps.as_mut(), mock_env(), operatorInfo.clone(), "Game001".to_string(), Expiration::Never {}).unwrap();
+        let err = execute_sweep(deps.as_mut(), mock_env(), operatorInfo.clone(), vec![coin(10, "uusd")]).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "operator11111".to_string() });
+        execute_sweep(deps.as_mut(), mock_env(), treasuryInfo.clone(), vec![coin(10, "uusd")]).unwrap();
+        let err = create_game(deps.as_mut(), mock_env(), treasuryInfo.clone(), "Game002".to_string(), Expiration::Never {}).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "treasury11111".to_string() });
+
+        // The admin only changes once the proposed one accepts
+        let err = accept_admin_role(deps.as_mut(), newAdminInfo.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::GenericErr { msg: String::from("No admin transfer is pending") }));
+        propose_new_admin(deps.as_mut(), adminInfo.clone(), "admin22222".to_string()).unwrap();
+        let roles = query_roles(deps.as_ref()).unwrap();
+        assert_eq!(roles.admin, Some("admin11111".to_string()));
+        assert_eq!(roles.pending_admin, Some("admin22222".to_string()));
+        let err = accept_admin_role(deps.as_mut(), operatorInfo.clone()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "operator11111".to_string() });
+        accept_admin_role(deps.as_mut(), newAdminInfo.clone()).unwrap();
+
+        let roles = query_roles(deps.as_ref()).unwrap();
+        assert_eq!(roles.admin, Some("admin22222".to_string()));
+        assert_eq!(roles.pending_admin, None);
+        let err = set_treasury(deps.as_mut(), adminInfo.clone(), "admin11111".to_string()).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { invoker: "admin11111".to_string() });
+        set_treasury(deps.as_mut(), newAdminInfo.clone(), "admin22222".to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_legacy_storage_layout() {
+        let mut deps = mock_dependencies(&[]);
+        // Storage as the 0.9.1 contract left it
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.9.1").unwrap();
+        deps.storage.set(b"config", br#"{"admin_address":"admin11111","minting_contract_address":"cwtoken11111","platform_fees_collector_wallet":"FEE_WALLET","astro_proxy_address":"ASTROPORT","platform_fee":"300","transaction_fee":"100","game_id":"Game001"}"#);
+        LEGACY_GAME_DETAILS.save(&mut deps.storage, "Game001".to_string(), &LegacyGameDetails {
+            game_id: "Game001".to_string(),
+            game_status: GAME_POOL_CLOSED,
+        }).unwrap();
+        for pool_id in ["1", "2"] {
+            LEGACY_POOL_DETAILS.save(&mut deps.storage, pool_id.to_string(), &PoolDetails {
+                pool_id: pool_id.to_string(),
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                current_teams_count: 1u32,
+                rewards_distributed: false,
+                pool_refund_status: false,
+                pool_reward_status: false,
+            }).unwrap();
+            LEGACY_POOL_TEAM_DETAILS.save(&mut deps.storage, (pool_id, "Gamer001"), &vec![PoolTeamDetails {
+                pool_id: pool_id.to_string(),
+                game_id: "Game001".to_string(),
+                pool_type: "oneToTwo".to_string(),
+                gamer_address: "Gamer001".to_string(),
+                team_id: format!("Team00{}", pool_id),
+                reward_amount: Uint128::zero(),
+                claimed_reward: false,
+                refund_amount: Uint128::zero(),
+                claimed_refund: false,
+                // Only pool 2 has results
+                team_points: if pool_id == "2" { 300u64 } else { 0u64 },
+                team_rank: if pool_id == "2" { 1u64 } else { 100000u64 },
+                team_changes: vec![],
+            }]).unwrap();
+        }
+        // Pool 1 was swapped last, pool 2 had rewards assigned last
+        let swap_info = SwapBalanceDetails {
+            balance_pre_swap: Uint128::zero(),
+            balance_post_swap: Uint128::from(18000u128),
+            exchange_rate: Uint128::from(100000u128),
+            ust_amount_swapped: Uint128::from(1800u128),
+            ust_for_rake: Uint128::from(200u128),
+        };
+        deps.storage.set(&LEGACY_CURRENT_REWARD_FOR_POOL.key("1".to_string()), &to_vec(&swap_info).unwrap());
+        LEGACY_CURRENT_REWARD_FOR_POOL.save(&mut deps.storage, "2".to_string(), &Uint128::from(5400u128)).unwrap();
+
+        let rsp = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(rsp.attributes[1].value, "0.9.1".to_string());
+        assert_eq!(get_contract_version(&deps.storage).unwrap().version, CONTRACT_VERSION.to_string());
+
+        let roles = query_roles(deps.as_ref()).unwrap();
+        assert_eq!(roles.admin, Some("admin11111".to_string()));
+        assert_eq!(roles.game_operator, Some("admin11111".to_string()));
+        assert_eq!(roles.treasury, Some("admin11111".to_string()));
+        // The config is rewritten without the admin and the game id
+        let migrated_config = deps.storage.get(b"config").unwrap();
+        assert_eq!(
+            migrated_config,
+            br#"{"minting_contract_address":"cwtoken11111","platform_fees_collector_wallet":"FEE_WALLET","astro_proxy_address":"ASTROPORT","platform_fee":"300","transaction_fee":"100"}"#.to_vec(),
+        );
+        let config = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(config.platform_fee, Uint128::from(300u128));
+        CONFIG.save(&mut deps.storage, &config).unwrap();
+        assert_eq!(deps.storage.get(b"config").unwrap(), migrated_config);
+        let game = query_game_details(&mut deps.storage, &mock_env().block, "Game001".to_string()).unwrap();
+        assert_eq!(game.game_status, GAME_POOL_CLOSED);
+        assert_eq!(game.entry_close_time, Expiration::Never {});
+
+        let all_pools = query_all_pools_in_game(&deps.storage, "Game001".to_string(), None, None).unwrap();
+        assert_eq!(all_pools.len(), 2);
+        assert_eq!(LEGACY_POOL_DETAILS.may_load(&deps.storage, "1".to_string()).unwrap(), None);
+        let gamer_pools = get_pool_teams_for_gamer(&deps.storage, "Gamer001".to_string()).unwrap();
+        assert_eq!(gamer_pools.len(), 2);
+        assert_eq!(gamer_pools[1][0].team_id, "Team002".to_string());
+        assert_eq!(LEGACY_POOL_TEAM_DETAILS.may_load(&deps.storage, ("1", "Gamer001")).unwrap(), None);
+        let leaderboard = query_pool_leaderboard(&deps.storage, "Game001".to_string(), "2".to_string(), None, None).unwrap();
+        assert_eq!(leaderboard.len(), 1);
+        assert_eq!(leaderboard[0].team_rank, 1u64);
+        assert_eq!(leaderboard[0].team_points, 300u64);
+
+        assert_eq!(SWAP_BALANCE_INFO.load(&deps.storage, "1".to_string()).unwrap(), swap_info);
+        assert_eq!(SWAP_BALANCE_INFO.may_load(&deps.storage, "2".to_string()).unwrap(), None);
+        let reward_details = POOL_REWARD_DETAILS.load(&deps.storage, ("Game001", "2")).unwrap();
+        assert_eq!(reward_details.reward_assigned, Uint128::from(5400u128));
+        let ledger = POOL_LEDGERS.load(&deps.storage, ("Game001", "2")).unwrap();
+        assert_eq!(ledger.rewards_assigned, Uint128::from(5400u128));
+        assert_eq!(deps.storage.get(&LEGACY_CURRENT_REWARD_FOR_POOL.key("1".to_string())), None);
+
+        // Migrating again keeps the new layout, downgrades and other contracts are refused
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert_eq!(query_all_pools_in_game(&deps.storage, "Game001".to_string(), None, None).unwrap().len(), 2);
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::GenericErr {
+            msg: format!("Cannot migrate from 99.0.0 to older version {}", CONTRACT_VERSION),
+        }));
+        set_contract_version(deps.as_mut().storage, "crates.io:cw20-base", "0.9.1").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert_eq!(err, ContractError::Std(StdError::GenericErr {
+            msg: String::from("Cannot migrate from crates.io:cw20-base"),
+        }));
+    }
+}